@@ -0,0 +1,326 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::traits::Weight;
+
+/// An error in the items given to `BinPacking`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BinPackingError {
+    /// An item is heavier than the bin capacity and cannot be packed.
+    ItemTooLarge {
+        /// The input index of the item.
+        index: usize,
+        /// The weight of the item.
+        weight: usize,
+    },
+}
+
+impl fmt::Display for BinPackingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BinPackingError::ItemTooLarge { index, weight } => {
+                write!(
+                    f,
+                    "item {index} of weight {weight} does not fit in an empty bin"
+                )
+            }
+        }
+    }
+}
+
+impl Error for BinPackingError {}
+
+/// The algorithm used by `BinPacking::pack`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BinPackingMethod {
+    /// Puts every item, in input order, into the first bin with enough room.
+    FirstFit,
+    /// Puts every item, in input order, into the fullest bin with enough room.
+    BestFit,
+    /// Sorts the items by decreasing weight, then applies First Fit.
+    FirstFitDecreasing,
+    /// Plain depth-first branch and bound, starting from the First Fit
+    /// Decreasing packing. Items are placed by decreasing weight into every
+    /// open bin with a distinct residual capacity or into a new bin, and a node
+    /// is pruned when the volume bound on the bins still needed reaches the
+    /// best packing found. The search stops early if it meets the L2 lower
+    /// bound. The running time is exponential in the worst case, so it is meant
+    /// for small instances.
+    Exact,
+}
+
+/// A packing of items into bins computed by `BinPacking::pack`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Packing<T> {
+    /// The items of every bin, each in input order.
+    pub bins: Vec<Vec<T>>,
+    /// The L2 lower bound on the number of bins of any packing.
+    pub lower_bound: usize,
+}
+
+/// A solver for the bin packing problem.
+///
+/// Every item must be packed into a bin, and the total weight of each bin
+/// must not exceed the capacity. The number of bins used is minimized.
+///
+/// # Examples
+///
+/// ```
+/// use knap::bin_packing::{BinPacking, BinPackingMethod};
+/// use knap::traits::Weight;
+///
+/// #[derive(Clone, Debug, PartialEq)]
+/// struct Parcel {
+///     id: char,
+///     weight: usize,
+/// }
+///
+/// impl Weight for Parcel {
+///     fn weight(&self) -> usize {
+///         self.weight
+///     }
+/// }
+///
+/// let parcels = vec![
+///     Parcel { id: 'A', weight: 3 },
+///     Parcel { id: 'B', weight: 6 },
+///     Parcel { id: 'C', weight: 4 },
+///     Parcel { id: 'D', weight: 7 },
+/// ];
+///
+/// let packing = BinPacking::new(parcels, 10)
+///     .unwrap()
+///     .pack(BinPackingMethod::FirstFitDecreasing);
+/// let bins: Vec<Vec<char>> = packing
+///     .bins
+///     .iter()
+///     .map(|bin| bin.iter().map(|parcel| parcel.id).collect())
+///     .collect();
+/// assert_eq!(bins, vec![vec!['A', 'D'], vec!['B', 'C']]);
+/// assert_eq!(packing.lower_bound, 2);
+/// ```
+#[derive(Debug)]
+pub struct BinPacking<T>
+where
+    T: Weight + Clone,
+{
+    items: Vec<T>,
+    capacity: usize,
+}
+
+impl<T> BinPacking<T>
+where
+    T: Weight + Clone,
+{
+    /// Creates a new `BinPacking` solver.
+    ///
+    /// # Arguments
+    ///
+    /// * `input_items`: An iterator over items that implement `Weight` and `Clone`.
+    /// * `capacity`: The capacity of every bin.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an item is heavier than the capacity.
+    pub fn new(
+        input_items: impl IntoIterator<Item = T>,
+        capacity: usize,
+    ) -> Result<Self, BinPackingError> {
+        let items: Vec<T> = input_items.into_iter().collect();
+        if let Some((index, item)) = items
+            .iter()
+            .enumerate()
+            .find(|(_, item)| item.weight() > capacity)
+        {
+            return Err(BinPackingError::ItemTooLarge {
+                index,
+                weight: item.weight(),
+            });
+        }
+        Ok(BinPacking { items, capacity })
+    }
+
+    /// Returns the L2 lower bound of Martello and Toth on the number of bins.
+    ///
+    /// For every threshold `a` in `{0} ∪ {w : w <= capacity / 2}`, the items
+    /// heavier than `capacity - a` each need their own bin, as do the items
+    /// heavier than half the capacity, and the items of weight in
+    /// `[a, capacity / 2]` need enough extra bins for the weight that does not
+    /// fit into the room left by the latter.
+    pub fn lower_bound(&self) -> usize {
+        let weights: Vec<usize> = self.items.iter().map(Weight::weight).collect();
+        if weights.is_empty() {
+            return 0;
+        }
+        let capacity = self.capacity;
+        if capacity == 0 {
+            return 1;
+        }
+
+        let mut thresholds: Vec<usize> = weights
+            .iter()
+            .copied()
+            .filter(|&w| 2 * w <= capacity)
+            .collect();
+        thresholds.push(0);
+        thresholds.sort_unstable();
+        thresholds.dedup();
+
+        let bound = thresholds
+            .into_iter()
+            .map(|a| {
+                let large = weights.iter().filter(|&&w| w > capacity - a).count();
+                let (medium, medium_weight) = weights
+                    .iter()
+                    .filter(|&&w| w <= capacity - a && 2 * w > capacity)
+                    .fold((0, 0), |(count, total), &w| (count + 1, total + w));
+                let small_weight: usize = weights
+                    .iter()
+                    .filter(|&&w| 2 * w <= capacity && w >= a)
+                    .sum();
+                let room = medium * capacity - medium_weight;
+                large + medium + small_weight.saturating_sub(room).div_ceil(capacity)
+            })
+            .max()
+            .unwrap_or(0);
+        bound.max(1)
+    }
+
+    /// Packs the items with the given method.
+    pub fn pack(&self, method: BinPackingMethod) -> Packing<T> {
+        let weights: Vec<usize> = self.items.iter().map(Weight::weight).collect();
+        let input_order: Vec<usize> = (0..weights.len()).collect();
+        let mut decreasing = input_order.clone();
+        decreasing.sort_by(|&a, &b| weights[b].cmp(&weights[a]).then(a.cmp(&b)));
+        let lower_bound = self.lower_bound();
+
+        let assignment = match method {
+            BinPackingMethod::FirstFit => first_fit(&input_order, &weights, self.capacity),
+            BinPackingMethod::BestFit => best_fit(&input_order, &weights, self.capacity),
+            BinPackingMethod::FirstFitDecreasing => first_fit(&decreasing, &weights, self.capacity),
+            BinPackingMethod::Exact => {
+                let incumbent = first_fit(&decreasing, &weights, self.capacity);
+                let mut search = Search {
+                    order: &decreasing,
+                    weights: &weights,
+                    capacity: self.capacity,
+                    lower_bound,
+                    best_count: bin_count(&incumbent),
+                    best: incumbent,
+                    assignment: vec![0; weights.len()],
+                    residuals: Vec::new(),
+                };
+                let total = weights.iter().sum();
+                search.branch(0, total);
+                search.best
+            }
+        };
+
+        let mut bins: Vec<Vec<T>> = vec![Vec::new(); bin_count(&assignment)];
+        for (idx, &bin) in assignment.iter().enumerate() {
+            bins[bin].push(self.items[idx].clone());
+        }
+        Packing { bins, lower_bound }
+    }
+}
+
+// Number of bins used by an assignment of bins to items.
+fn bin_count(assignment: &[usize]) -> usize {
+    assignment.iter().max().map_or(0, |&bin| bin + 1)
+}
+
+// Returns the bin of every item packed by First Fit in the given order.
+pub(crate) fn first_fit(order: &[usize], weights: &[usize], capacity: usize) -> Vec<usize> {
+    let mut assignment = vec![0; weights.len()];
+    let mut residuals: Vec<usize> = Vec::new();
+    for &idx in order {
+        let bin = match residuals.iter().position(|&room| room >= weights[idx]) {
+            Some(bin) => bin,
+            None => {
+                residuals.push(capacity);
+                residuals.len() - 1
+            }
+        };
+        residuals[bin] -= weights[idx];
+        assignment[idx] = bin;
+    }
+    assignment
+}
+
+// Returns the bin of every item packed by Best Fit in the given order.
+fn best_fit(order: &[usize], weights: &[usize], capacity: usize) -> Vec<usize> {
+    let mut assignment = vec![0; weights.len()];
+    let mut residuals: Vec<usize> = Vec::new();
+    for &idx in order {
+        let fullest = (0..residuals.len())
+            .filter(|&bin| residuals[bin] >= weights[idx])
+            .min_by_key(|&bin| residuals[bin]);
+        let bin = match fullest {
+            Some(bin) => bin,
+            None => {
+                residuals.push(capacity);
+                residuals.len() - 1
+            }
+        };
+        residuals[bin] -= weights[idx];
+        assignment[idx] = bin;
+    }
+    assignment
+}
+
+// State of the depth-first branch and bound.
+struct Search<'a> {
+    // Items by decreasing weight.
+    order: &'a [usize],
+    weights: &'a [usize],
+    capacity: usize,
+    lower_bound: usize,
+    best_count: usize,
+    best: Vec<usize>,
+    assignment: Vec<usize>,
+    // Room left in every open bin.
+    residuals: Vec<usize>,
+}
+
+impl Search<'_> {
+    // Packs `order[position..]`, whose total weight is `remaining`.
+    fn branch(&mut self, position: usize, remaining: usize) {
+        if self.best_count == self.lower_bound {
+            return;
+        }
+        let room: usize = self.residuals.iter().sum();
+        let extra = remaining
+            .saturating_sub(room)
+            .div_ceil(self.capacity.max(1));
+        if self.residuals.len() + extra >= self.best_count {
+            return;
+        }
+        if position == self.order.len() {
+            self.best_count = self.residuals.len();
+            self.best = self.assignment.clone();
+            return;
+        }
+
+        let idx = self.order[position];
+        let weight = self.weights[idx];
+        // Bins with the same room lead to symmetric subtrees.
+        let mut tried: Vec<usize> = Vec::new();
+        for bin in 0..self.residuals.len() {
+            let room = self.residuals[bin];
+            if room < weight || tried.contains(&room) {
+                continue;
+            }
+            tried.push(room);
+            self.residuals[bin] -= weight;
+            self.assignment[idx] = bin;
+            self.branch(position + 1, remaining - weight);
+            self.residuals[bin] += weight;
+        }
+        if self.residuals.len() + 1 < self.best_count {
+            self.residuals.push(self.capacity - weight);
+            self.assignment[idx] = self.residuals.len() - 1;
+            self.branch(position + 1, remaining - weight);
+            self.residuals.pop();
+        }
+    }
+}
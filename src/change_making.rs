@@ -0,0 +1,161 @@
+use std::collections::VecDeque;
+
+use crate::optimal::{unbounded_counts, unbounded_table};
+use crate::traits::Weight;
+
+/// A solver for the change-making problem.
+///
+/// Each coin type has a denomination given by `Weight`. The solver represents
+/// an amount as a sum of denominations using as few coins as possible. Coin
+/// types are unlimited by default, and their number of copies can be bounded
+/// with `with_limits`. Coin types with a zero denomination are never used.
+///
+/// Without limits, the unbounded knapsack dynamic program is used; with
+/// limits, a dynamic program over coin types takes, for every amount, the best
+/// allowed count of each type with a sliding window minimum over the amounts
+/// that differ by multiples of its denomination. Both run in O(n * amount)
+/// time for `n` coin types.
+///
+/// # Examples
+///
+/// ```
+/// use knap::change_making::ChangeMaking;
+/// use knap::traits::Weight;
+///
+/// #[derive(Clone, Debug, PartialEq)]
+/// struct Coin(usize);
+///
+/// impl Weight for Coin {
+///     fn weight(&self) -> usize {
+///         self.0
+///     }
+/// }
+///
+/// let coins = vec![Coin(1), Coin(3), Coin(4)];
+///
+/// // The greedy choice 4 + 1 + 1 uses one coin more.
+/// let change = ChangeMaking::new(coins.clone()).make_change(6);
+/// assert_eq!(change, Some(vec![Coin(3), Coin(3)]));
+///
+/// // With a single coin of 3, the greedy choice is the best left.
+/// let change = ChangeMaking::new(coins).with_limits([5, 1, 5]).make_change(6);
+/// assert_eq!(change, Some(vec![Coin(1), Coin(1), Coin(4)]));
+/// ```
+#[derive(Debug)]
+pub struct ChangeMaking<T>
+where
+    T: Weight + Clone,
+{
+    coins: Vec<T>,
+    limits: Option<Vec<usize>>,
+}
+
+impl<T> ChangeMaking<T>
+where
+    T: Weight + Clone,
+{
+    /// Creates a new `ChangeMaking` solver with unlimited coins.
+    ///
+    /// # Arguments
+    ///
+    /// * `coins`: An iterator over coin types that implement `Weight` and `Clone`.
+    pub fn new(coins: impl IntoIterator<Item = T>) -> Self {
+        ChangeMaking {
+            coins: coins.into_iter().collect(),
+            limits: None,
+        }
+    }
+
+    /// Limits the number of copies of every coin type, in input order.
+    ///
+    /// Coin types without a limit stay unlimited.
+    pub fn with_limits(mut self, limits: impl IntoIterator<Item = usize>) -> Self {
+        self.limits = Some(limits.into_iter().collect());
+        self
+    }
+
+    /// Returns the fewest coins summing to `amount`, grouped by coin type in
+    /// input order, or `None` if the amount cannot be represented.
+    pub fn make_change(&self, amount: usize) -> Option<Vec<T>> {
+        let denominations: Vec<usize> = self.coins.iter().map(Weight::weight).collect();
+        let counts = match &self.limits {
+            None => {
+                let (best, last) = unbounded_table(
+                    &denominations,
+                    amount,
+                    0,
+                    |count, _| count + 1,
+                    |a, b| a < b,
+                );
+                best[amount]?;
+                unbounded_counts(&denominations, &last, amount)
+            }
+            Some(limits) => bounded_counts(&denominations, limits, amount)?,
+        };
+
+        Some(
+            self.coins
+                .iter()
+                .zip(counts)
+                .flat_map(|(coin, count)| std::iter::repeat_n(coin.clone(), count))
+                .collect(),
+        )
+    }
+}
+
+// Fewest coins summing exactly to `amount` when coin type `i` may be used at
+// most `limits[i]` times, returning the count of every type.
+fn bounded_counts(denominations: &[usize], limits: &[usize], amount: usize) -> Option<Vec<usize>> {
+    let n = denominations.len();
+    let limit = |i: usize| {
+        let most = amount.checked_div(denominations[i]).unwrap_or(0);
+        limits.get(i).map_or(most, |&limit| limit.min(most))
+    };
+
+    // `dp[i][c]` is the fewest coins of the first `i` types summing to `c`.
+    let mut dp = vec![vec![None; amount + 1]; n + 1];
+    dp[0][0] = Some(0);
+    for i in 1..=n {
+        let denomination = denominations[i - 1];
+        if denomination == 0 {
+            dp[i] = dp[i - 1].clone();
+            continue;
+        }
+        // Along the amounts `c = r + j * denomination`, taking `k` coins of
+        // this type reaches `c` from step `j - k`, so `dp[i][c]` is `j` plus
+        // the minimum of `dp[i - 1] - step` over the last `limit + 1` steps.
+        let window = limit(i - 1);
+        for r in 0..denomination.min(amount + 1) {
+            // Steps in the window, by increasing `dp[i - 1] - step`.
+            let mut queue: VecDeque<(usize, usize)> = VecDeque::new();
+            for (j, c) in (r..=amount).step_by(denomination).enumerate() {
+                if let Some(count) = dp[i - 1][c] {
+                    // Drops steps no better than `j`; `best - s >= count - j`
+                    // is compared with both sides shifted to stay unsigned.
+                    while queue.back().is_some_and(|&(s, best)| best + j >= count + s) {
+                        queue.pop_back();
+                    }
+                    queue.push_back((j, count));
+                }
+                while queue.front().is_some_and(|&(s, _)| s + window < j) {
+                    queue.pop_front();
+                }
+                dp[i][c] = queue.front().map(|&(s, best)| best + j - s);
+            }
+        }
+    }
+
+    dp[n][amount]?;
+    let mut counts = vec![0; n];
+    let mut c = amount;
+    for i in (1..=n).rev() {
+        let denomination = denominations[i - 1];
+        let target = dp[i][c];
+        let k = (0..=limit(i - 1))
+            .take_while(|&k| k * denomination <= c)
+            .find(|&k| dp[i - 1][c - k * denomination].map(|count| count + k) == target)?;
+        counts[i - 1] = k;
+        c -= k * denomination;
+    }
+    Some(counts)
+}
@@ -0,0 +1,328 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::traits::{Value, Weight};
+
+/// An error in the conflicts given to `ConflictKnapsackIterator` or
+/// `GreedyKnapsackIterator::with_conflicts`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConflictError {
+    /// A conflict refers to an item index that is out of bounds.
+    IndexOutOfBounds(usize),
+}
+
+impl fmt::Display for ConflictError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConflictError::IndexOutOfBounds(index) => {
+                write!(f, "conflict refers to item {index}, which is out of bounds")
+            }
+        }
+    }
+}
+
+impl Error for ConflictError {}
+
+/// An iterator that yields items of an optimal knapsack solution in which no
+/// two selected items conflict.
+///
+/// Conflicts are given as pairs of input indices, forming an undirected
+/// conflict graph. The instance is solved exactly by depth-first branch and
+/// bound over items sorted by value-to-weight ratio. Each node is bounded by
+/// the smaller of the fractional knapsack bound on the still-compatible items
+/// and a clique bound: the items are partitioned into cliques of the conflict
+/// graph, and at most one item per clique can be selected. The search starts
+/// from the solution of the greedy variant
+/// (`GreedyKnapsackIterator::with_conflicts`).
+///
+/// # Examples
+///
+/// ```
+/// use knap::conflict::ConflictKnapsackIterator;
+/// use knap::traits::{Value, Weight};
+///
+/// #[derive(Clone, Debug, PartialEq)]
+/// struct Item {
+///     id: char,
+///     weight: usize,
+///     value: usize,
+/// }
+///
+/// impl Weight for Item {
+///     fn weight(&self) -> usize {
+///         self.weight
+///     }
+/// }
+///
+/// impl Value for Item {
+///     fn value(&self) -> usize {
+///         self.value
+///     }
+/// }
+///
+/// let items = vec![
+///     Item { id: 'A', weight: 10, value: 60 },
+///     Item { id: 'B', weight: 20, value: 100 },
+///     Item { id: 'C', weight: 30, value: 120 },
+/// ];
+///
+/// // B and C are incompatible.
+/// let ids: Vec<char> = ConflictKnapsackIterator::new(items, 50, [(1, 2)])
+///     .unwrap()
+///     .map(|item| item.id)
+///     .collect();
+/// assert_eq!(ids, vec!['A', 'C']);
+/// ```
+#[derive(Debug)]
+pub struct ConflictKnapsackIterator<T>
+where
+    T: Weight + Value + Clone,
+{
+    items: Vec<T>,
+    capacity: usize,
+    conflicts: Vec<Vec<usize>>,
+    solution_items: Vec<T>,
+    current_index: usize,
+    // Ensures the search is run only once.
+    computed: bool,
+}
+
+impl<T> ConflictKnapsackIterator<T>
+where
+    T: Weight + Value + Clone,
+{
+    /// Creates a new `ConflictKnapsackIterator`.
+    ///
+    /// # Arguments
+    ///
+    /// * `input_items`: An iterator over items that implement `Weight`, `Value`, and `Clone`.
+    /// * `capacity`: The maximum capacity of the knapsack.
+    /// * `conflicts`: Pairs of input indices that cannot be selected together.
+    ///   Pairs of an index with itself are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a conflict refers to an index out of bounds.
+    pub fn new(
+        input_items: impl IntoIterator<Item = T>,
+        capacity: usize,
+        conflicts: impl IntoIterator<Item = (usize, usize)>,
+    ) -> Result<Self, ConflictError> {
+        let items: Vec<T> = input_items.into_iter().collect();
+        let conflicts = conflict_adjacency(items.len(), conflicts)?;
+        Ok(ConflictKnapsackIterator {
+            items,
+            capacity,
+            conflicts,
+            solution_items: Vec::new(),
+            current_index: 0,
+            computed: false,
+        })
+    }
+
+    fn compute_solution(&mut self) {
+        let n = self.items.len();
+        let weights: Vec<usize> = self.items.iter().map(Weight::weight).collect();
+        let values: Vec<usize> = self.items.iter().map(Value::value).collect();
+
+        let ratio = |idx: usize| match (weights[idx], values[idx]) {
+            (0, 0) => -1.0,
+            (0, _) => f64::MAX,
+            (weight, value) => value as f64 / weight as f64,
+        };
+        let mut order: Vec<usize> = (0..n).collect();
+        order.sort_by(|&a, &b| ratio(b).total_cmp(&ratio(a)).then(a.cmp(&b)));
+
+        let incumbent: Vec<usize> =
+            greedy_with_conflicts(&order, &weights, self.capacity, &self.conflicts);
+        let mut search = Search {
+            order: &order,
+            weights: &weights,
+            values: &values,
+            conflicts: &self.conflicts,
+            clique_of: clique_partition(&order, &self.conflicts),
+            best_value: incumbent.iter().map(|&idx| values[idx]).sum(),
+            best: incumbent,
+            chosen: Vec::new(),
+            blocked: vec![0; n],
+        };
+        search.branch(0, self.capacity, 0);
+
+        let mut best = search.best;
+        best.sort_unstable();
+        self.solution_items = best
+            .into_iter()
+            .map(|idx| self.items[idx].clone())
+            .collect();
+    }
+}
+
+impl<T> Iterator for ConflictKnapsackIterator<T>
+where
+    T: Weight + Value + Clone,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.computed {
+            self.compute_solution();
+            self.computed = true;
+        }
+
+        if self.current_index < self.solution_items.len() {
+            let item = self.solution_items[self.current_index].clone();
+            self.current_index += 1;
+            Some(item)
+        } else {
+            None
+        }
+    }
+}
+
+// State of the depth-first branch and bound.
+struct Search<'a> {
+    order: &'a [usize],
+    weights: &'a [usize],
+    values: &'a [usize],
+    conflicts: &'a [Vec<usize>],
+    // Clique id of every item.
+    clique_of: Vec<usize>,
+    best_value: usize,
+    best: Vec<usize>,
+    chosen: Vec<usize>,
+    // Number of chosen items conflicting with each item.
+    blocked: Vec<usize>,
+}
+
+impl Search<'_> {
+    fn branch(&mut self, position: usize, remaining: usize, value: usize) {
+        if value > self.best_value {
+            self.best_value = value;
+            self.best = self.chosen.clone();
+        }
+        if position == self.order.len()
+            || value + self.bound(position, remaining) <= self.best_value
+        {
+            return;
+        }
+
+        let idx = self.order[position];
+        if self.blocked[idx] == 0 && self.weights[idx] <= remaining {
+            self.chosen.push(idx);
+            for &other in &self.conflicts[idx] {
+                self.blocked[other] += 1;
+            }
+            self.branch(
+                position + 1,
+                remaining - self.weights[idx],
+                value + self.values[idx],
+            );
+            for &other in &self.conflicts[idx] {
+                self.blocked[other] -= 1;
+            }
+            self.chosen.pop();
+        }
+        self.branch(position + 1, remaining, value);
+    }
+
+    // Upper bound on the value still obtainable from `order[position..]`.
+    fn bound(&self, position: usize, remaining: usize) -> usize {
+        let free = self.order[position..]
+            .iter()
+            .copied()
+            .filter(|&idx| self.blocked[idx] == 0 && self.weights[idx] <= remaining);
+
+        let mut fractional = 0.0;
+        let mut room = remaining;
+        for idx in free.clone() {
+            if self.weights[idx] <= room {
+                room -= self.weights[idx];
+                fractional += self.values[idx] as f64;
+            } else {
+                fractional += self.values[idx] as f64 * room as f64 / self.weights[idx] as f64;
+                break;
+            }
+        }
+
+        // Clique ids are below the number of items.
+        let mut clique_best = vec![0; self.clique_of.len()];
+        for idx in free {
+            let clique = self.clique_of[idx];
+            clique_best[clique] = clique_best[clique].max(self.values[idx]);
+        }
+        let clique_bound: usize = clique_best.iter().sum();
+
+        (fractional.floor() as usize).min(clique_bound)
+    }
+}
+
+// Builds the adjacency lists of the conflict graph.
+pub(crate) fn conflict_adjacency(
+    n: usize,
+    conflicts: impl IntoIterator<Item = (usize, usize)>,
+) -> Result<Vec<Vec<usize>>, ConflictError> {
+    let mut adjacency = vec![Vec::new(); n];
+    for (a, b) in conflicts {
+        if let Some(&index) = [a, b].iter().find(|&&index| index >= n) {
+            return Err(ConflictError::IndexOutOfBounds(index));
+        }
+        if a != b {
+            adjacency[a].push(b);
+            adjacency[b].push(a);
+        }
+    }
+    for neighbors in &mut adjacency {
+        neighbors.sort_unstable();
+        neighbors.dedup();
+    }
+    Ok(adjacency)
+}
+
+// Takes items in `order` as long as they fit and do not conflict with an
+// already taken item.
+pub(crate) fn greedy_with_conflicts(
+    order: &[usize],
+    weights: &[usize],
+    capacity: usize,
+    conflicts: &[Vec<usize>],
+) -> Vec<usize> {
+    let mut blocked = vec![false; weights.len()];
+    let mut remaining = capacity;
+    let mut taken = Vec::new();
+    for &idx in order {
+        if blocked[idx] || weights[idx] > remaining {
+            continue;
+        }
+        remaining -= weights[idx];
+        taken.push(idx);
+        for &other in &conflicts[idx] {
+            blocked[other] = true;
+        }
+    }
+    taken
+}
+
+// Greedily partitions the items into cliques of the conflict graph, returning
+// the clique id of every item.
+fn clique_partition(order: &[usize], conflicts: &[Vec<usize>]) -> Vec<usize> {
+    let mut clique_of = vec![0; conflicts.len()];
+    let mut cliques: Vec<Vec<usize>> = Vec::new();
+    for &idx in order {
+        let fitting = cliques.iter().position(|members| {
+            members
+                .iter()
+                .all(|member| conflicts[idx].binary_search(member).is_ok())
+        });
+        match fitting {
+            Some(clique) => {
+                cliques[clique].push(idx);
+                clique_of[idx] = clique;
+            }
+            None => {
+                clique_of[idx] = cliques.len();
+                cliques.push(vec![idx]);
+            }
+        }
+    }
+    clique_of
+}
@@ -0,0 +1,301 @@
+use crate::traits::{Value, Weight};
+
+/// An iterator that yields items of an optimal covering (minimization) knapsack solution.
+///
+/// This is the counterpart of `KnapsackIterator`: the value of each item is
+/// interpreted as a cost, and the solver selects the cheapest subset whose
+/// total weight reaches at least `demand`. It is solved exactly with dynamic
+/// programming over the demand, where coverage beyond the demand is clamped.
+///
+/// If the total weight of all items is below the demand, there is no feasible
+/// solution and the iterator yields nothing; use `is_feasible` to tell this
+/// apart from a zero demand.
+///
+/// # Examples
+///
+/// ```
+/// use knap::covering::CoveringKnapsackIterator;
+/// use knap::traits::{Value, Weight};
+///
+/// #[derive(Clone, Debug, PartialEq)]
+/// struct Supplier {
+///     id: char,
+///     capacity: usize,
+///     cost: usize,
+/// }
+///
+/// impl Weight for Supplier {
+///     fn weight(&self) -> usize {
+///         self.capacity
+///     }
+/// }
+///
+/// impl Value for Supplier {
+///     fn value(&self) -> usize {
+///         self.cost
+///     }
+/// }
+///
+/// let suppliers = vec![
+///     Supplier { id: 'A', capacity: 10, cost: 50 },
+///     Supplier { id: 'B', capacity: 6, cost: 20 },
+///     Supplier { id: 'C', capacity: 5, cost: 20 },
+/// ];
+///
+/// let ids: Vec<char> = CoveringKnapsackIterator::new(suppliers, 11)
+///     .map(|supplier| supplier.id)
+///     .collect();
+/// assert_eq!(ids, vec!['B', 'C']);
+/// ```
+#[derive(Debug)]
+pub struct CoveringKnapsackIterator<T>
+where
+    T: Weight + Value + Clone,
+{
+    items: Vec<T>,
+    demand: usize,
+    solution_items: Vec<T>,
+    feasible: bool,
+    current_index: usize,
+    // Ensures DP is run only once.
+    computed: bool,
+}
+
+impl<T> CoveringKnapsackIterator<T>
+where
+    T: Weight + Value + Clone,
+{
+    /// Creates a new `CoveringKnapsackIterator`.
+    ///
+    /// # Arguments
+    ///
+    /// * `input_items`: An iterator over items that implement `Weight`, `Value` (as cost), and `Clone`.
+    /// * `demand`: The minimum total weight to cover.
+    pub fn new(input_items: impl IntoIterator<Item = T>, demand: usize) -> Self {
+        CoveringKnapsackIterator {
+            items: input_items.into_iter().collect(),
+            demand,
+            solution_items: Vec::new(),
+            feasible: false,
+            current_index: 0,
+            computed: false,
+        }
+    }
+
+    /// Returns `true` if the items can cover the demand.
+    pub fn is_feasible(&mut self) -> bool {
+        if !self.computed {
+            self.compute_solution();
+        }
+        self.feasible
+    }
+
+    fn compute_solution(&mut self) {
+        self.computed = true;
+        let entries: Vec<(usize, usize)> = self
+            .items
+            .iter()
+            .map(|item| (item.weight(), item.value()))
+            .collect();
+        if let Some((_, indices)) = min_cover(&entries, self.demand) {
+            self.feasible = true;
+            self.solution_items = indices
+                .into_iter()
+                .map(|idx| self.items[idx].clone())
+                .collect();
+        }
+    }
+}
+
+impl<T> Iterator for CoveringKnapsackIterator<T>
+where
+    T: Weight + Value + Clone,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.computed {
+            self.compute_solution();
+        }
+
+        if self.current_index < self.solution_items.len() {
+            let item = self.solution_items[self.current_index].clone();
+            self.current_index += 1;
+            Some(item)
+        } else {
+            None
+        }
+    }
+}
+
+/// An iterator that yields items of a greedy covering knapsack solution.
+///
+/// Items are scanned by increasing cost-to-weight ratio and added to a partial
+/// solution as long as it stays below the demand. Whenever the next item would
+/// complete the coverage, the partial solution plus that item is recorded as a
+/// candidate instead, and the cheapest candidate is returned. Items that are
+/// no longer needed to cover the demand are then dropped, most expensive
+/// first. This is a 2-approximation of the optimal cost.
+///
+/// # Examples
+///
+/// ```
+/// use knap::covering::GreedyCoveringIterator;
+/// use knap::traits::{Value, Weight};
+///
+/// #[derive(Clone, Debug, PartialEq)]
+/// struct Supplier {
+///     id: char,
+///     capacity: usize,
+///     cost: usize,
+/// }
+///
+/// impl Weight for Supplier {
+///     fn weight(&self) -> usize {
+///         self.capacity
+///     }
+/// }
+///
+/// impl Value for Supplier {
+///     fn value(&self) -> usize {
+///         self.cost
+///     }
+/// }
+///
+/// let suppliers = vec![
+///     Supplier { id: 'A', capacity: 10, cost: 50 },
+///     Supplier { id: 'B', capacity: 6, cost: 20 },
+///     Supplier { id: 'C', capacity: 5, cost: 20 },
+/// ];
+///
+/// let cost: usize = GreedyCoveringIterator::new(suppliers, 11)
+///     .map(|supplier| supplier.cost)
+///     .sum();
+/// assert_eq!(cost, 40);
+/// ```
+#[derive(Debug)]
+pub struct GreedyCoveringIterator<T>
+where
+    T: Weight + Value + Clone,
+{
+    solution_items: Vec<T>,
+    current_index: usize,
+}
+
+impl<T> GreedyCoveringIterator<T>
+where
+    T: Weight + Value + Clone,
+{
+    /// Creates a new `GreedyCoveringIterator`, computing the greedy solution.
+    ///
+    /// If the items cannot cover the demand, the iterator yields nothing.
+    ///
+    /// # Arguments
+    ///
+    /// * `input_items`: An iterator over items that implement `Weight`, `Value` (as cost), and `Clone`.
+    /// * `demand`: The minimum total weight to cover.
+    pub fn new(input_items: impl IntoIterator<Item = T>, demand: usize) -> Self {
+        let items: Vec<T> = input_items.into_iter().collect();
+        let ratio = |item: &T| match (item.weight(), item.value()) {
+            (0, _) => f64::MAX,
+            (weight, cost) => cost as f64 / weight as f64,
+        };
+        let mut order: Vec<usize> = (0..items.len()).collect();
+        order.sort_by(|&a, &b| {
+            ratio(&items[a])
+                .total_cmp(&ratio(&items[b]))
+                .then(a.cmp(&b))
+        });
+
+        let mut partial: Vec<usize> = Vec::new();
+        let mut covered = 0;
+        let mut cost = 0;
+        let mut best: Option<(usize, Vec<usize>)> = (demand == 0).then(|| (0, Vec::new()));
+        for idx in order {
+            let item = &items[idx];
+            if covered + item.weight() >= demand {
+                if best
+                    .as_ref()
+                    .is_none_or(|(best_cost, _)| cost + item.value() < *best_cost)
+                {
+                    let mut candidate = partial.clone();
+                    candidate.push(idx);
+                    best = Some((cost + item.value(), candidate));
+                }
+            } else {
+                partial.push(idx);
+                covered += item.weight();
+                cost += item.value();
+            }
+        }
+
+        let solution_items = best
+            .map(|(_, mut indices)| {
+                let mut covered: usize = indices.iter().map(|&idx| items[idx].weight()).sum();
+                let mut by_cost = indices.clone();
+                by_cost.sort_by_key(|&idx| std::cmp::Reverse(items[idx].value()));
+                for idx in by_cost {
+                    if covered - items[idx].weight() >= demand {
+                        covered -= items[idx].weight();
+                        indices.retain(|&kept| kept != idx);
+                    }
+                }
+                indices.into_iter().map(|idx| items[idx].clone()).collect()
+            })
+            .unwrap_or_default();
+        GreedyCoveringIterator {
+            solution_items,
+            current_index: 0,
+        }
+    }
+}
+
+impl<T> Iterator for GreedyCoveringIterator<T>
+where
+    T: Weight + Value + Clone,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.current_index < self.solution_items.len() {
+            let item = self.solution_items[self.current_index].clone();
+            self.current_index += 1;
+            Some(item)
+        } else {
+            None
+        }
+    }
+}
+
+// Solves a 0/1 covering problem over `(amount, cost)` entries: the cheapest
+// subset whose amounts sum to at least `demand`. Returns the cost and the
+// selected indices in increasing order, or `None` if the demand cannot be met.
+pub(crate) fn min_cover(entries: &[(usize, usize)], demand: usize) -> Option<(usize, Vec<usize>)> {
+    let n = entries.len();
+    // `dp[i][d]` is the cheapest cost covering at least `d` with the first `i` entries.
+    let mut dp = vec![vec![None; demand + 1]; n + 1];
+    dp[0][0] = Some(0);
+    for i in 1..=n {
+        let (amount, cost) = entries[i - 1];
+        for d in 0..=demand {
+            let without: Option<usize> = dp[i - 1][d];
+            let with = dp[i - 1][d.saturating_sub(amount)].map(|c| c + cost);
+            dp[i][d] = match (without, with) {
+                (Some(a), Some(b)) => Some(a.min(b)),
+                (a, b) => a.or(b),
+            };
+        }
+    }
+
+    let total = dp[n][demand]?;
+    let mut d = demand;
+    let mut indices = Vec::new();
+    for i in (1..=n).rev() {
+        if dp[i][d] != dp[i - 1][d] {
+            indices.push(i - 1);
+            d = d.saturating_sub(entries[i - 1].0);
+        }
+    }
+    indices.reverse();
+    Some((total, indices))
+}
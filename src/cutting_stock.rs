@@ -0,0 +1,336 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::bin_packing::first_fit;
+use crate::optimal::{unbounded_counts, unbounded_table};
+use crate::traits::Weight;
+
+// Tolerance of the floating-point comparisons of the LP.
+const EPSILON: f64 = 1e-6;
+
+// Maximum number of patterns generated before the LP is considered solved.
+const MAX_COLUMNS: usize = 1000;
+
+/// Defines the behavior for orders that request a number of pieces.
+pub trait Demand {
+    /// Returns the number of pieces requested.
+    fn demand(&self) -> usize;
+}
+
+/// An error in the orders given to `CuttingStock`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CuttingStockError {
+    /// An order has zero width or is wider than the rolls.
+    InvalidWidth {
+        /// The input index of the order.
+        index: usize,
+        /// The width of the order.
+        width: usize,
+    },
+}
+
+impl fmt::Display for CuttingStockError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CuttingStockError::InvalidWidth { index, width } => {
+                write!(
+                    f,
+                    "order {index} has width {width}, which is zero or wider than the roll"
+                )
+            }
+        }
+    }
+}
+
+impl Error for CuttingStockError {}
+
+/// A way of cutting a roll, repeated a number of times.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CuttingPattern<T> {
+    /// The orders cut from the roll and the number of pieces of each, in input order.
+    pub pieces: Vec<(T, usize)>,
+    /// The number of rolls cut with this pattern.
+    pub multiplicity: usize,
+}
+
+/// A cutting plan computed by `CuttingStock::solve`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CuttingPlan<T> {
+    /// The patterns used by the plan.
+    pub patterns: Vec<CuttingPattern<T>>,
+    /// The total number of rolls cut.
+    pub rolls: usize,
+    /// The rounded-up lower bound on the LP relaxation, and thus on the number of rolls.
+    pub lower_bound: usize,
+}
+
+/// A solver for the cutting stock problem.
+///
+/// Orders request a number of pieces (see `Demand`) of a given width (see
+/// `Weight`), to be cut from rolls of a fixed width. The number of rolls is
+/// minimized; pieces cut beyond the demand are allowed.
+///
+/// The LP relaxation over cutting patterns is solved by column generation,
+/// starting from one pattern per order. The restricted master problem is
+/// solved with the simplex method, and a new pattern is priced out by the
+/// unbounded knapsack dynamic program over the dual prices, until no pattern
+/// has a reduced cost below zero. The LP solution is rounded down, and the
+/// pieces still missing are packed with First Fit Decreasing.
+///
+/// The lower bound is the Farley bound, the restricted LP value divided by the
+/// largest pattern price, which stays valid when column generation stops
+/// before convergence and equals the LP value once it converges.
+///
+/// # Examples
+///
+/// ```
+/// use knap::cutting_stock::{CuttingStock, Demand};
+/// use knap::traits::Weight;
+///
+/// #[derive(Clone, Debug, PartialEq)]
+/// struct Order {
+///     width: usize,
+///     pieces: usize,
+/// }
+///
+/// impl Weight for Order {
+///     fn weight(&self) -> usize {
+///         self.width
+///     }
+/// }
+///
+/// impl Demand for Order {
+///     fn demand(&self) -> usize {
+///         self.pieces
+///     }
+/// }
+///
+/// let orders = vec![
+///     Order { width: 45, pieces: 4 },
+///     Order { width: 36, pieces: 3 },
+///     Order { width: 31, pieces: 6 },
+/// ];
+///
+/// let plan = CuttingStock::new(orders, 100).unwrap().solve();
+/// // Two rolls cut 45 + 45 and three cut 36 + 31 + 31.
+/// assert_eq!(plan.rolls, 5);
+/// assert_eq!(plan.lower_bound, 5);
+/// ```
+#[derive(Debug)]
+pub struct CuttingStock<T>
+where
+    T: Weight + Demand + Clone,
+{
+    orders: Vec<T>,
+    roll_width: usize,
+}
+
+impl<T> CuttingStock<T>
+where
+    T: Weight + Demand + Clone,
+{
+    /// Creates a new `CuttingStock` solver.
+    ///
+    /// # Arguments
+    ///
+    /// * `orders`: An iterator over orders that implement `Weight`, `Demand`, and `Clone`.
+    /// * `roll_width`: The width of every roll.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an order with a positive demand has zero width or
+    /// is wider than the rolls.
+    pub fn new(
+        orders: impl IntoIterator<Item = T>,
+        roll_width: usize,
+    ) -> Result<Self, CuttingStockError> {
+        let orders: Vec<T> = orders.into_iter().collect();
+        if let Some((index, order)) = orders.iter().enumerate().find(|(_, order)| {
+            order.demand() > 0 && (order.weight() == 0 || order.weight() > roll_width)
+        }) {
+            return Err(CuttingStockError::InvalidWidth {
+                index,
+                width: order.weight(),
+            });
+        }
+        Ok(CuttingStock { orders, roll_width })
+    }
+
+    /// Computes a cutting plan and a lower bound on the number of rolls.
+    pub fn solve(&self) -> CuttingPlan<T> {
+        let active: Vec<usize> = (0..self.orders.len())
+            .filter(|&idx| self.orders[idx].demand() > 0)
+            .collect();
+        let widths: Vec<usize> = active
+            .iter()
+            .map(|&idx| self.orders[idx].weight())
+            .collect();
+        let demands: Vec<f64> = active
+            .iter()
+            .map(|&idx| self.orders[idx].demand() as f64)
+            .collect();
+
+        // Patterns as the number of pieces of every active order.
+        let mut patterns: Vec<Vec<usize>> = (0..active.len())
+            .map(|i| {
+                let mut pattern = vec![0; active.len()];
+                pattern[i] = self.roll_width / widths[i];
+                pattern
+            })
+            .collect();
+        let (mut lp_bound, mut usage) = (0.0f64, Vec::new());
+        while !active.is_empty() {
+            let rows: Vec<Vec<f64>> = patterns
+                .iter()
+                .map(|pattern| pattern.iter().map(|&count| count as f64).collect())
+                .collect();
+            let (lp_value, prices, duals) = simplex(&rows, &demands);
+            usage = duals;
+
+            let (best, last) = unbounded_table(
+                &widths,
+                self.roll_width,
+                0.0,
+                |total, i| total + prices[i],
+                |a, b| a > b,
+            );
+            let Some((fill, price)) = best
+                .iter()
+                .enumerate()
+                .filter_map(|(fill, price)| price.map(|price| (fill, price)))
+                .max_by(|a, b| a.1.total_cmp(&b.1))
+            else {
+                break;
+            };
+            lp_bound = lp_bound.max(lp_value / price.max(1.0));
+            let pattern = unbounded_counts(&widths, &last, fill);
+            if price <= 1.0 + EPSILON
+                || patterns.contains(&pattern)
+                || patterns.len() >= MAX_COLUMNS
+            {
+                break;
+            }
+            patterns.push(pattern);
+        }
+
+        // Round down, then pack the missing pieces.
+        let mut multiplicities: Vec<usize> = usage
+            .iter()
+            .map(|&x| (x + EPSILON).floor() as usize)
+            .collect();
+        let mut missing: Vec<usize> = Vec::new();
+        for (i, &idx) in active.iter().enumerate() {
+            let produced: usize = patterns
+                .iter()
+                .zip(&multiplicities)
+                .map(|(pattern, &multiplicity)| pattern[i] * multiplicity)
+                .sum();
+            let remaining = self.orders[idx].demand().saturating_sub(produced);
+            missing.extend(std::iter::repeat_n(i, remaining));
+        }
+        let missing_widths: Vec<usize> = missing.iter().map(|&i| widths[i]).collect();
+        let mut decreasing: Vec<usize> = (0..missing.len()).collect();
+        decreasing.sort_by(|&a, &b| missing_widths[b].cmp(&missing_widths[a]).then(a.cmp(&b)));
+        let bins = first_fit(&decreasing, &missing_widths, self.roll_width);
+        let mut repaired = vec![vec![0; active.len()]; bins.iter().max().map_or(0, |&bin| bin + 1)];
+        for (piece, &bin) in bins.iter().enumerate() {
+            repaired[bin][missing[piece]] += 1;
+        }
+        for pattern in repaired {
+            match patterns.iter().position(|existing| *existing == pattern) {
+                Some(position) => multiplicities[position] += 1,
+                None => {
+                    patterns.push(pattern);
+                    multiplicities.push(1);
+                }
+            }
+        }
+
+        let plan: Vec<CuttingPattern<T>> = patterns
+            .into_iter()
+            .zip(multiplicities)
+            .filter(|&(_, multiplicity)| multiplicity > 0)
+            .map(|(pattern, multiplicity)| CuttingPattern {
+                pieces: pattern
+                    .into_iter()
+                    .enumerate()
+                    .filter(|&(_, count)| count > 0)
+                    .map(|(i, count)| (self.orders[active[i]].clone(), count))
+                    .collect(),
+                multiplicity,
+            })
+            .collect();
+        CuttingPlan {
+            rolls: plan.iter().map(|pattern| pattern.multiplicity).sum(),
+            patterns: plan,
+            lower_bound: (lp_bound - EPSILON).ceil().max(0.0) as usize,
+        }
+    }
+}
+
+// Maximizes `objective · y` subject to `row · y <= 1` for every row and
+// `y >= 0` with the tableau simplex method, using Bland's rule against
+// cycling. This is the dual of the master problem, so the dual value of every
+// row is the usage of the matching pattern. Returns the optimal value, `y`,
+// and the dual values.
+fn simplex(rows: &[Vec<f64>], objective: &[f64]) -> (f64, Vec<f64>, Vec<f64>) {
+    let m = rows.len();
+    let n = objective.len();
+    let rhs = n + m;
+    let mut tableau: Vec<Vec<f64>> = rows
+        .iter()
+        .enumerate()
+        .map(|(k, row)| {
+            let mut line = row.clone();
+            line.extend((0..m).map(|slack| if slack == k { 1.0 } else { 0.0 }));
+            line.push(1.0);
+            line
+        })
+        .collect();
+    let mut costs: Vec<f64> = objective.iter().map(|&c| -c).collect();
+    costs.extend(std::iter::repeat_n(0.0, m + 1));
+    let mut basis: Vec<usize> = (n..n + m).collect();
+
+    while let Some(entering) = (0..rhs).find(|&j| costs[j] < -EPSILON) {
+        let leaving = (0..m)
+            .filter(|&k| tableau[k][entering] > EPSILON)
+            .min_by(|&a, &b| {
+                let ratio_a = tableau[a][rhs] / tableau[a][entering];
+                let ratio_b = tableau[b][rhs] / tableau[b][entering];
+                ratio_a.total_cmp(&ratio_b).then(basis[a].cmp(&basis[b]))
+            });
+        // Every variable appears in a row with a positive coefficient, so the
+        // problem is bounded.
+        let Some(leaving) = leaving else {
+            break;
+        };
+
+        let pivot = tableau[leaving][entering];
+        for value in &mut tableau[leaving] {
+            *value /= pivot;
+        }
+        let pivot_row = tableau[leaving].clone();
+        for (k, row) in tableau.iter_mut().enumerate() {
+            if k != leaving && row[entering].abs() > EPSILON {
+                let factor = row[entering];
+                for (value, &p) in row.iter_mut().zip(&pivot_row) {
+                    *value -= factor * p;
+                }
+            }
+        }
+        let factor = costs[entering];
+        for (value, &p) in costs.iter_mut().zip(&pivot_row) {
+            *value -= factor * p;
+        }
+        basis[leaving] = entering;
+    }
+
+    let mut y = vec![0.0; n];
+    for (k, &variable) in basis.iter().enumerate() {
+        if variable < n {
+            y[variable] = tableau[k][rhs];
+        }
+    }
+    let duals = (0..m).map(|k| costs[n + k].max(0.0)).collect();
+    (costs[rhs], y, duals)
+}
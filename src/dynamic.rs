@@ -0,0 +1,149 @@
+use crate::optimal::{backtrack, dp_row};
+use crate::traits::{Value, Weight};
+
+/// A knapsack instance that supports adding and removing items.
+///
+/// The structure keeps one dynamic programming row per item, up to a fixed
+/// maximum capacity. Inserting an item appends a single row in
+/// O(`max_capacity`). Removing the item at index `i` drops its row and rebuilds
+/// only the rows of the items after it, so removing recently inserted items is
+/// cheap. Queries are answered as by `CapacityProfile`: values in constant time
+/// and optimal subsets in O(n).
+///
+/// # Examples
+///
+/// ```
+/// use knap::dynamic::DynamicKnapsack;
+/// use knap::traits::{Value, Weight};
+///
+/// #[derive(Clone, Debug, PartialEq)]
+/// struct Item {
+///     id: char,
+///     weight: usize,
+///     value: usize,
+/// }
+///
+/// impl Weight for Item {
+///     fn weight(&self) -> usize {
+///         self.weight
+///     }
+/// }
+///
+/// impl Value for Item {
+///     fn value(&self) -> usize {
+///         self.value
+///     }
+/// }
+///
+/// let mut knapsack = DynamicKnapsack::new(50);
+/// knapsack.insert(Item { id: 'A', weight: 10, value: 60 });
+/// knapsack.insert(Item { id: 'B', weight: 20, value: 100 });
+/// knapsack.insert(Item { id: 'C', weight: 30, value: 120 });
+/// assert_eq!(knapsack.value(50), Some(220));
+///
+/// knapsack.remove(1);
+/// assert_eq!(knapsack.value(50), Some(180));
+/// ```
+#[derive(Debug)]
+pub struct DynamicKnapsack<T>
+where
+    T: Weight + Value + Clone,
+{
+    items: Vec<T>,
+    entries: Vec<(usize, usize)>,
+    max_capacity: usize,
+    // `dp[i]` is the DP row for the first `i` items.
+    dp: Vec<Vec<usize>>,
+}
+
+impl<T> DynamicKnapsack<T>
+where
+    T: Weight + Value + Clone,
+{
+    /// Creates an empty `DynamicKnapsack` able to answer queries up to `max_capacity`.
+    pub fn new(max_capacity: usize) -> Self {
+        DynamicKnapsack {
+            items: Vec::new(),
+            entries: Vec::new(),
+            max_capacity,
+            dp: vec![vec![0; max_capacity + 1]],
+        }
+    }
+
+    /// Returns the largest capacity this structure can answer queries for.
+    pub fn max_capacity(&self) -> usize {
+        self.max_capacity
+    }
+
+    /// Returns the current items, in insertion order.
+    pub fn items(&self) -> &[T] {
+        &self.items
+    }
+
+    /// Returns the number of items.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns `true` if there are no items.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Appends an item, extending the DP table by one row.
+    pub fn insert(&mut self, item: T) {
+        let entry = (item.weight(), item.value());
+        let row = dp_row(&self.dp[self.dp.len() - 1], entry);
+        self.items.push(item);
+        self.entries.push(entry);
+        self.dp.push(row);
+    }
+
+    /// Removes and returns the item at `index`, shifting later items down.
+    ///
+    /// Only the rows of the items after `index` are rebuilt.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn remove(&mut self, index: usize) -> T {
+        let item = self.items.remove(index);
+        self.entries.remove(index);
+        self.dp.truncate(index + 1);
+        for &entry in &self.entries[index..] {
+            let row = dp_row(&self.dp[self.dp.len() - 1], entry);
+            self.dp.push(row);
+        }
+        item
+    }
+
+    /// Returns the optimal value for `capacity`, or `None` if it exceeds `max_capacity`.
+    pub fn value(&self, capacity: usize) -> Option<usize> {
+        self.dp[self.items.len()].get(capacity).copied()
+    }
+
+    /// Returns an optimal subset for `capacity`, or `None` if it exceeds `max_capacity`.
+    pub fn solution(&self, capacity: usize) -> Option<Vec<T>> {
+        if capacity > self.max_capacity {
+            return None;
+        }
+        let indices = backtrack(&self.dp, &self.entries, capacity);
+        Some(
+            indices
+                .into_iter()
+                .map(|idx| self.items[idx].clone())
+                .collect(),
+        )
+    }
+}
+
+impl<T> Extend<T> for DynamicKnapsack<T>
+where
+    T: Weight + Value + Clone,
+{
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.insert(item);
+        }
+    }
+}
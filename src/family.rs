@@ -0,0 +1,201 @@
+use std::collections::BTreeMap;
+
+use crate::optimal::{backtrack, dp_table};
+use crate::traits::{Value, Weight};
+
+/// Defines the behavior for items that belong to a product family.
+///
+/// Selecting any item of a family incurs the family's `FamilySetup` once.
+pub trait Family {
+    /// Returns the identifier of the family the item belongs to.
+    fn family(&self) -> usize;
+}
+
+/// The fixed setup incurred when at least one item of a family is selected.
+///
+/// The setup `weight` consumes capacity and the setup `value` is a cost
+/// subtracted from the total value.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FamilySetup {
+    /// The capacity consumed by the setup.
+    pub weight: usize,
+    /// The value lost by the setup.
+    pub value: usize,
+}
+
+/// An iterator that yields items of an optimal knapsack solution with family setups.
+///
+/// Items are grouped by `Family`, and selecting at least one item of a family
+/// consumes its setup weight and costs its setup value. The solver maximizes
+/// the total value of the selected items minus the setup values of the used
+/// families, subject to the items and setups fitting in the capacity.
+///
+/// The problem is solved exactly by dynamic programming over families: a
+/// knapsack table is computed within each family, and families are then
+/// combined by distributing the capacity among them, in `O(F * capacity²)`
+/// for `F` families. Items are yielded in input order.
+///
+/// # Examples
+///
+/// ```
+/// use knap::family::{Family, FamilyKnapsackIterator, FamilySetup};
+/// use knap::traits::{Value, Weight};
+///
+/// #[derive(Clone, Debug, PartialEq)]
+/// struct Product {
+///     id: char,
+///     line: usize,
+///     weight: usize,
+///     value: usize,
+/// }
+///
+/// impl Weight for Product {
+///     fn weight(&self) -> usize {
+///         self.weight
+///     }
+/// }
+///
+/// impl Value for Product {
+///     fn value(&self) -> usize {
+///         self.value
+///     }
+/// }
+///
+/// impl Family for Product {
+///     fn family(&self) -> usize {
+///         self.line
+///     }
+/// }
+///
+/// let products = vec![
+///     Product { id: 'A', line: 0, weight: 3, value: 10 },
+///     Product { id: 'B', line: 1, weight: 3, value: 9 },
+///     Product { id: 'C', line: 1, weight: 3, value: 9 },
+/// ];
+/// let setups = vec![
+///     (0, FamilySetup { weight: 2, value: 0 }),
+///     (1, FamilySetup { weight: 1, value: 0 }),
+/// ];
+///
+/// // Without setups A and B would be picked; line 1 is cheaper to set up.
+/// let ids: Vec<char> = FamilyKnapsackIterator::new(products, 7, setups)
+///     .map(|product| product.id)
+///     .collect();
+/// assert_eq!(ids, vec!['B', 'C']);
+/// ```
+#[derive(Debug)]
+pub struct FamilyKnapsackIterator<T>
+where
+    T: Weight + Value + Family + Clone,
+{
+    items: Vec<T>,
+    capacity: usize,
+    setups: BTreeMap<usize, FamilySetup>,
+    solution_items: Vec<T>,
+    current_index: usize,
+    // Ensures DP is run only once.
+    computed: bool,
+}
+
+impl<T> FamilyKnapsackIterator<T>
+where
+    T: Weight + Value + Family + Clone,
+{
+    /// Creates a new `FamilyKnapsackIterator`.
+    ///
+    /// Families without a setup have no setup weight or value.
+    ///
+    /// # Arguments
+    ///
+    /// * `input_items`: An iterator over items that implement `Weight`, `Value`, `Family`, and `Clone`.
+    /// * `capacity`: The maximum capacity of the knapsack.
+    /// * `setups`: Pairs of a family identifier and its setup.
+    pub fn new(
+        input_items: impl IntoIterator<Item = T>,
+        capacity: usize,
+        setups: impl IntoIterator<Item = (usize, FamilySetup)>,
+    ) -> Self {
+        FamilyKnapsackIterator {
+            items: input_items.into_iter().collect(),
+            capacity,
+            setups: setups.into_iter().collect(),
+            solution_items: Vec::new(),
+            current_index: 0,
+            computed: false,
+        }
+    }
+
+    fn compute_solution(&mut self) {
+        let capacity = self.capacity;
+        let mut families: BTreeMap<usize, Vec<usize>> = BTreeMap::new();
+        for (idx, item) in self.items.iter().enumerate() {
+            families.entry(item.family()).or_default().push(idx);
+        }
+
+        // `best[c]` is the best net value of the families processed so far
+        // within capacity `c`; `choices` records, per family and capacity, the
+        // capacity given to the family including its setup, if it is used.
+        let mut best = vec![0i64; capacity + 1];
+        let mut stages = Vec::with_capacity(families.len());
+        for (family, members) in &families {
+            let setup = self.setups.get(family).copied().unwrap_or_default();
+            let entries: Vec<(usize, usize)> = members
+                .iter()
+                .map(|&idx| (self.items[idx].weight(), self.items[idx].value()))
+                .collect();
+            let table = dp_table(&entries, capacity.saturating_sub(setup.weight));
+            let gains = &table[entries.len()];
+
+            let mut next = best.clone();
+            let mut choices = vec![None; capacity + 1];
+            for c in setup.weight..=capacity {
+                for k in setup.weight..=c {
+                    let gain = gains[k - setup.weight] as i64 - setup.value as i64;
+                    if best[c - k] + gain > next[c] {
+                        next[c] = best[c - k] + gain;
+                        choices[c] = Some(k);
+                    }
+                }
+            }
+            best = next;
+            stages.push((members, entries, table, setup, choices));
+        }
+
+        let mut selected = Vec::new();
+        let mut c = capacity;
+        for (members, entries, table, setup, choices) in stages.into_iter().rev() {
+            if let Some(k) = choices[c] {
+                let local = backtrack(&table, &entries, k - setup.weight);
+                selected.extend(local.into_iter().map(|i| members[i]));
+                c -= k;
+            }
+        }
+        selected.sort_unstable();
+        self.solution_items = selected
+            .into_iter()
+            .map(|idx| self.items[idx].clone())
+            .collect();
+    }
+}
+
+impl<T> Iterator for FamilyKnapsackIterator<T>
+where
+    T: Weight + Value + Family + Clone,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.computed {
+            self.compute_solution();
+            self.computed = true;
+        }
+
+        if self.current_index < self.solution_items.len() {
+            let item = self.solution_items[self.current_index].clone();
+            self.current_index += 1;
+            Some(item)
+        } else {
+            None
+        }
+    }
+}
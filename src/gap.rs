@@ -0,0 +1,316 @@
+use crate::optimal::solve;
+
+/// Defines the behavior for tasks whose weight and value depend on the agent
+/// they are assigned to.
+pub trait Assignable {
+    /// Returns the capacity the task consumes when assigned to `agent`.
+    fn weight_for(&self, agent: usize) -> usize;
+
+    /// Returns the value earned when the task is assigned to `agent`.
+    fn value_for(&self, agent: usize) -> usize;
+}
+
+/// An assignment of tasks to agents computed by `GeneralizedAssignment`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Assignment {
+    /// The agent of every task, in input order, or `None` if it is unassigned.
+    pub agents: Vec<Option<usize>>,
+    /// The total value of the assignment.
+    pub value: usize,
+    /// An upper bound on the value of any feasible assignment.
+    pub upper_bound: usize,
+}
+
+impl Assignment {
+    /// Returns `true` if the assignment is proven optimal by its upper bound.
+    pub fn is_optimal(&self) -> bool {
+        self.value == self.upper_bound
+    }
+}
+
+/// A solver for the generalized assignment problem.
+///
+/// Every task is assigned to at most one agent, and the tasks assigned to an
+/// agent must fit in its capacity. Both the weight and the value of a task
+/// depend on its agent (see `Assignable`), and the total value is maximized.
+///
+/// A first assignment is built with the regret heuristic of Martello and
+/// Toth: the task whose best agent is most preferable to its second best is
+/// assigned first, and each task is then moved to a better agent with enough
+/// room left. The upper bound comes from the Lagrangian relaxation of the
+/// one-agent-per-task constraints, which splits into one knapsack per agent
+/// solved exactly by dynamic programming. The multipliers are improved by
+/// subgradient steps, and every relaxed solution is repaired into a feasible
+/// assignment that may improve on the heuristic.
+///
+/// # Examples
+///
+/// ```
+/// use knap::gap::{Assignable, GeneralizedAssignment};
+///
+/// struct Task {
+///     // Hours needed and revenue earned by each of the two teams.
+///     hours: [usize; 2],
+///     revenue: [usize; 2],
+/// }
+///
+/// impl Assignable for Task {
+///     fn weight_for(&self, agent: usize) -> usize {
+///         self.hours[agent]
+///     }
+///
+///     fn value_for(&self, agent: usize) -> usize {
+///         self.revenue[agent]
+///     }
+/// }
+///
+/// let tasks = vec![
+///     Task { hours: [4, 2], revenue: [10, 6] },
+///     Task { hours: [3, 5], revenue: [8, 9] },
+///     Task { hours: [2, 2], revenue: [5, 5] },
+/// ];
+///
+/// let assignment = GeneralizedAssignment::new(tasks, [6, 4]).solve();
+/// assert_eq!(assignment.agents, vec![Some(1), Some(0), Some(0)]);
+/// assert_eq!(assignment.value, 19);
+/// assert!(assignment.is_optimal());
+/// ```
+#[derive(Debug)]
+pub struct GeneralizedAssignment<T>
+where
+    T: Assignable,
+{
+    tasks: Vec<T>,
+    capacities: Vec<usize>,
+    iterations: usize,
+}
+
+impl<T> GeneralizedAssignment<T>
+where
+    T: Assignable,
+{
+    /// Creates a new `GeneralizedAssignment` solver.
+    ///
+    /// # Arguments
+    ///
+    /// * `tasks`: An iterator over tasks that implement `Assignable`.
+    /// * `capacities`: The capacity of every agent; agents are numbered in this order.
+    pub fn new(
+        tasks: impl IntoIterator<Item = T>,
+        capacities: impl IntoIterator<Item = usize>,
+    ) -> Self {
+        GeneralizedAssignment {
+            tasks: tasks.into_iter().collect(),
+            capacities: capacities.into_iter().collect(),
+            iterations: 100,
+        }
+    }
+
+    /// Sets the maximum number of subgradient iterations. Defaults to 100.
+    pub fn with_iterations(mut self, iterations: usize) -> Self {
+        self.iterations = iterations;
+        self
+    }
+
+    /// Computes an assignment and an upper bound on the optimal value.
+    pub fn solve(&self) -> Assignment {
+        let mut best = self.regret_heuristic();
+        self.improve(&mut best);
+        let mut best_value = self.value(&best);
+
+        let n = self.tasks.len();
+        let mut multipliers = vec![0usize; n];
+        // Every task earns at most its best value among agents it fits.
+        let mut upper_bound: usize = self
+            .tasks
+            .iter()
+            .map(|task| {
+                (0..self.capacities.len())
+                    .filter(|&agent| task.weight_for(agent) <= self.capacities[agent])
+                    .map(|agent| task.value_for(agent))
+                    .max()
+                    .unwrap_or(0)
+            })
+            .sum();
+        // Polyak step scale, halved when the bound stalls.
+        let mut scale = 2.0;
+        let mut stalled = 0;
+        for _ in 0..self.iterations {
+            let (bound, relaxed) = self.relaxation(&multipliers);
+            if bound < upper_bound {
+                upper_bound = bound;
+                stalled = 0;
+            } else {
+                stalled += 1;
+                if stalled == 5 {
+                    scale /= 2.0;
+                    stalled = 0;
+                }
+            }
+
+            let mut candidate = self.repair(&relaxed);
+            self.improve(&mut candidate);
+            let value = self.value(&candidate);
+            if value > best_value {
+                best_value = value;
+                best = candidate;
+            }
+            if upper_bound <= best_value {
+                break;
+            }
+
+            // Projected subgradient of the relaxed constraints `sum_j x_ij <= 1`.
+            let subgradient: Vec<i64> = (0..n)
+                .map(|task| {
+                    let slack = 1 - relaxed[task].len() as i64;
+                    if slack > 0 && multipliers[task] == 0 {
+                        0
+                    } else {
+                        slack
+                    }
+                })
+                .collect();
+            let norm: i64 = subgradient.iter().map(|g| g * g).sum();
+            if norm == 0 {
+                break;
+            }
+            let step = scale * (upper_bound - best_value) as f64 / norm as f64;
+            for (multiplier, &g) in multipliers.iter_mut().zip(&subgradient) {
+                let updated = *multiplier as f64 - (step * g as f64).round();
+                *multiplier = updated.max(0.0) as usize;
+            }
+        }
+
+        Assignment {
+            agents: best,
+            value: best_value,
+            upper_bound: upper_bound.max(best_value),
+        }
+    }
+
+    fn value(&self, agents: &[Option<usize>]) -> usize {
+        agents
+            .iter()
+            .zip(&self.tasks)
+            .filter_map(|(agent, task)| agent.map(|agent| task.value_for(agent)))
+            .sum()
+    }
+
+    // Martello–Toth regret heuristic, with value per unit of weight as the
+    // desirability of an agent for a task.
+    fn regret_heuristic(&self) -> Vec<Option<usize>> {
+        let desirability =
+            |task: &T, agent: usize| match (task.weight_for(agent), task.value_for(agent)) {
+                (0, 0) => 0.0,
+                (0, _) => f64::MAX,
+                (weight, value) => value as f64 / weight as f64,
+            };
+        let mut agents = vec![None; self.tasks.len()];
+        let mut remaining = self.capacities.clone();
+        loop {
+            // The next assignment as `(regret, task, agent)`.
+            let mut next: Option<(f64, usize, usize)> = None;
+            for (idx, task) in self.tasks.iter().enumerate() {
+                if agents[idx].is_some() {
+                    continue;
+                }
+                let mut feasible: Vec<(f64, usize)> = (0..remaining.len())
+                    .filter(|&agent| {
+                        task.value_for(agent) > 0 && task.weight_for(agent) <= remaining[agent]
+                    })
+                    .map(|agent| (desirability(task, agent), agent))
+                    .collect();
+                feasible.sort_by(|a, b| b.0.total_cmp(&a.0).then(a.1.cmp(&b.1)));
+                let Some(&(first, agent)) = feasible.first() else {
+                    continue;
+                };
+                let regret = feasible
+                    .get(1)
+                    .map_or(f64::MAX, |&(second, _)| first - second);
+                if next.is_none_or(|(best_regret, _, _)| regret > best_regret) {
+                    next = Some((regret, idx, agent));
+                }
+            }
+            let Some((_, idx, agent)) = next else {
+                break;
+            };
+            agents[idx] = Some(agent);
+            remaining[agent] -= self.tasks[idx].weight_for(agent);
+        }
+        agents
+    }
+
+    // Moves every task to the agent with the largest value that has room for it.
+    fn improve(&self, agents: &mut [Option<usize>]) {
+        let mut remaining = self.capacities.clone();
+        for (agent, task) in agents.iter().zip(&self.tasks) {
+            if let Some(agent) = *agent {
+                remaining[agent] -= task.weight_for(agent);
+            }
+        }
+        for (idx, task) in self.tasks.iter().enumerate() {
+            let current = agents[idx].map_or(0, |agent| task.value_for(agent));
+            let better = (0..remaining.len())
+                .filter(|&agent| {
+                    let room = remaining[agent]
+                        + agents[idx]
+                            .filter(|&a| a == agent)
+                            .map_or(0, |a| task.weight_for(a));
+                    task.value_for(agent) > current && task.weight_for(agent) <= room
+                })
+                .max_by(|&a, &b| task.value_for(a).cmp(&task.value_for(b)).then(b.cmp(&a)));
+            if let Some(agent) = better {
+                if let Some(old) = agents[idx] {
+                    remaining[old] += task.weight_for(old);
+                }
+                remaining[agent] -= task.weight_for(agent);
+                agents[idx] = Some(agent);
+            }
+        }
+    }
+
+    // Solves the Lagrangian relaxation for the given multipliers, returning
+    // its value and the agents selecting each task.
+    fn relaxation(&self, multipliers: &[usize]) -> (usize, Vec<Vec<usize>>) {
+        let mut selected = vec![Vec::new(); self.tasks.len()];
+        let mut bound: usize = multipliers.iter().sum();
+        for (agent, &capacity) in self.capacities.iter().enumerate() {
+            let candidates: Vec<usize> = (0..self.tasks.len())
+                .filter(|&idx| self.tasks[idx].value_for(agent) > multipliers[idx])
+                .collect();
+            let entries: Vec<(usize, usize)> = candidates
+                .iter()
+                .map(|&idx| {
+                    let task = &self.tasks[idx];
+                    (
+                        task.weight_for(agent),
+                        task.value_for(agent) - multipliers[idx],
+                    )
+                })
+                .collect();
+            // Unlike `KnapsackIterator`, `solve` takes weightless tasks even at
+            // capacity zero, which the bound needs to be valid.
+            let (value, taken) = solve(&entries, capacity);
+            bound += value;
+            for k in taken {
+                selected[candidates[k]].push(agent);
+            }
+        }
+        (bound, selected)
+    }
+
+    // Turns a relaxed solution into a feasible assignment: each task keeps its
+    // most valuable selecting agent, and unassigned tasks are then placed on
+    // the most valuable agent with room left.
+    fn repair(&self, relaxed: &[Vec<usize>]) -> Vec<Option<usize>> {
+        let mut agents = vec![None; self.tasks.len()];
+        for (idx, task) in self.tasks.iter().enumerate() {
+            agents[idx] = relaxed[idx]
+                .iter()
+                .copied()
+                .max_by(|&a, &b| task.value_for(a).cmp(&task.value_for(b)).then(b.cmp(&a)));
+        }
+        self.improve(&mut agents);
+        agents
+    }
+}
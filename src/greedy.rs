@@ -1,10 +1,12 @@
+use crate::conflict::{conflict_adjacency, ConflictError};
+use crate::tie_break::TieBreak;
 use crate::traits::{Value, Weight};
 
 /// An iterator that yields items based on a greedy approximation
 /// for the knapsack problem.
 ///
-/// This iterator computes the greedy solution upon creation and then
-/// yields the selected items one by one.
+/// This iterator computes the greedy solution on the first call to `next`
+/// and then yields the selected items one by one.
 ///
 /// # Examples
 ///
@@ -56,8 +58,16 @@ pub struct GreedyKnapsackIterator<T>
 where
     T: Weight + Value + Clone,
 {
+    items: Vec<T>,
+    capacity: usize,
+    tie_break: Option<TieBreak<T>>,
+    conflicts: Vec<Vec<usize>>,
+    min_items: usize,
+    max_items: Option<usize>,
     solution_items: Vec<T>,
     current_index: usize,
+    // Ensures the greedy pass is run only once.
+    computed: bool,
 }
 
 impl<T> GreedyKnapsackIterator<T>
@@ -68,8 +78,19 @@ where
     ///
     /// Items are sorted by their value-to-weight ratio in descending order,
     /// and items are picked as long as they fit. Items with zero weight
-    /// and positive value are prioritized.
-    fn calculate_greedy_items(items_list: &[T], capacity_val: usize) -> Vec<T> {
+    /// and positive value are prioritized. Items with equal ratios are ordered
+    /// by `tie_break` when one is given. Items conflicting with an already
+    /// picked item are skipped.
+    ///
+    /// With item limits, picking stops at `max_items`, and an item is skipped
+    /// if taking it would leave no room for the lightest compatible items still
+    /// needed to reach `min_items`; those are added at the end. If `min_items` cannot be
+    /// reached, no items are picked.
+    fn calculate_greedy_items(&self) -> Vec<T> {
+        let items_list = &self.items;
+        let capacity_val = self.capacity;
+        let tie_break = self.tie_break;
+        let conflicts = &self.conflicts;
         if items_list.is_empty() || capacity_val == 0 {
             return Vec::new();
         }
@@ -94,6 +115,9 @@ where
         items_with_meta.sort_by(|a, b| {
             let ratio_cmp = b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal);
             if ratio_cmp == std::cmp::Ordering::Equal {
+                if let Some(policy) = &tie_break {
+                    return policy.compare_items((a.0, &items_list[a.0]), (b.0, &items_list[b.0]));
+                }
                 // If ratios are equal (e.g., both are MAX for zero-weight items),
                 // prioritize by value for zero-weight items, or by original index for stability otherwise.
                 if a.1 == f64::MAX && b.1 == f64::MAX {
@@ -108,23 +132,80 @@ where
 
         let mut result_items = Vec::new();
         let mut current_capacity = capacity_val;
+        let mut blocked = vec![false; items_list.len()];
+        let mut taken = vec![false; items_list.len()];
+        let mut by_weight: Vec<usize> = (0..items_list.len()).collect();
+        by_weight.sort_by_key(|&idx| items_list[idx].weight());
+        let conflicting = |a: usize, b: usize| {
+            conflicts
+                .get(a)
+                .is_some_and(|neighbors| neighbors.binary_search(&b).is_ok())
+        };
+        // Total weight of the `count` items added by the final lightest-first
+        // pass if `skip` were taken now, or `None` if that pass would run out
+        // of items that are neither taken nor blocked.
+        let lightest =
+            |taken: &[bool], blocked: &[bool], skip: usize, count: usize| -> Option<usize> {
+                let mut chosen: Vec<usize> = Vec::new();
+                for &idx in &by_weight {
+                    if chosen.len() == count {
+                        break;
+                    }
+                    let available = !taken[idx] && !blocked[idx] && idx != skip;
+                    if available && !chosen.iter().chain([&skip]).any(|&c| conflicting(c, idx)) {
+                        chosen.push(idx);
+                    }
+                }
+                (chosen.len() == count)
+                    .then(|| chosen.iter().map(|&idx| items_list[idx].weight()).sum())
+            };
+        let take =
+            |idx: usize, result_items: &mut Vec<T>, taken: &mut [bool], blocked: &mut [bool]| {
+                result_items.push(items_list[idx].clone());
+                taken[idx] = true;
+                for &other in conflicts.get(idx).into_iter().flatten() {
+                    blocked[other] = true;
+                }
+            };
 
         for (original_idx, _ratio, _value) in items_with_meta {
+            if self.max_items.is_some_and(|max| result_items.len() >= max) {
+                break;
+            }
             let item = &items_list[original_idx];
             let item_weight = item.weight();
 
-            if item_weight <= current_capacity {
-                result_items.push(item.clone());
+            if item_weight <= current_capacity && !blocked[original_idx] {
+                let still_needed = self.min_items.saturating_sub(result_items.len() + 1);
+                let rest = lightest(&taken, &blocked, original_idx, still_needed);
+                if rest.is_none_or(|weight| weight > current_capacity - item_weight) {
+                    continue;
+                }
+                take(original_idx, &mut result_items, &mut taken, &mut blocked);
+                current_capacity -= item_weight;
+            }
+        }
+
+        for &idx in &by_weight {
+            if result_items.len() >= self.min_items {
+                break;
+            }
+            let item_weight = items_list[idx].weight();
+            if !taken[idx] && !blocked[idx] && item_weight <= current_capacity {
+                take(idx, &mut result_items, &mut taken, &mut blocked);
                 current_capacity -= item_weight;
             }
         }
+        if result_items.len() < self.min_items {
+            return Vec::new();
+        }
         result_items
     }
 
     /// Creates a new `GreedyKnapsackIterator`.
     ///
-    /// It computes the greedy solution for the given items and capacity
-    /// and prepares to iterate over this solution.
+    /// The greedy solution for the given items and capacity is computed
+    /// lazily, when the iterator is first advanced.
     ///
     /// # Arguments
     ///
@@ -169,14 +250,59 @@ where
     /// // The iterator is now ready to yield items from the greedy solution.
     /// ```
     pub fn new(input_items: impl IntoIterator<Item = T>, capacity: usize) -> Self {
-        let items_vec: Vec<T> = input_items.into_iter().collect();
-        let solution_items = Self::calculate_greedy_items(&items_vec, capacity);
+        let items: Vec<T> = input_items.into_iter().collect();
 
         GreedyKnapsackIterator {
-            solution_items,
+            items,
+            capacity,
+            tie_break: None,
+            conflicts: Vec::new(),
+            min_items: 0,
+            max_items: None,
+            solution_items: Vec::new(),
             current_index: 0,
+            computed: false,
         }
     }
+
+    /// Sets the policy used to order items with the same value-to-weight ratio.
+    ///
+    /// Without a policy, equally dense items are taken in input order and
+    /// zero-weight items are taken by decreasing value.
+    pub fn with_tie_break(mut self, tie_break: TieBreak<T>) -> Self {
+        self.tie_break = Some(tie_break);
+        self
+    }
+
+    /// Forbids selecting items that conflict with an already selected item.
+    ///
+    /// Conflicts are pairs of input indices; see `ConflictKnapsackIterator`
+    /// for the exact counterpart.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a conflict refers to an index out of bounds.
+    pub fn with_conflicts(
+        mut self,
+        conflicts: impl IntoIterator<Item = (usize, usize)>,
+    ) -> Result<Self, ConflictError> {
+        self.conflicts = conflict_adjacency(self.items.len(), conflicts)?;
+        Ok(self)
+    }
+
+    /// Limits the solution to at most `max_items` items.
+    pub fn with_max_items(mut self, max_items: usize) -> Self {
+        self.max_items = Some(max_items);
+        self
+    }
+
+    /// Requires the solution to contain at least `min_items` items.
+    ///
+    /// If the greedy pass cannot reach `min_items`, the iterator yields nothing.
+    pub fn with_min_items(mut self, min_items: usize) -> Self {
+        self.min_items = min_items;
+        self
+    }
 }
 
 impl<T> Iterator for GreedyKnapsackIterator<T>
@@ -189,6 +315,11 @@ where
     ///
     /// Returns `None` when the iteration is finished.
     fn next(&mut self) -> Option<Self::Item> {
+        if !self.computed {
+            self.solution_items = self.calculate_greedy_items();
+            self.computed = true;
+        }
+
         if self.current_index < self.solution_items.len() {
             let item = self.solution_items[self.current_index].clone();
             self.current_index += 1;
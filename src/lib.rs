@@ -5,9 +5,33 @@
 //!
 //! # Features
 //!
-//! - Optimal knapsack solver (`KnapsackIterator`).
+//! - Optimal knapsack solver (`KnapsackIterator`), with the inverse query for the lightest subset
+//!   reaching a target value.
+//! - Enumeration of every optimal solution (`AllOptimalSolutionsIterator`).
+//! - Enumeration of the k best solutions (`KBestKnapsackIterator`).
 //! - Greedy knapsack solver (`GreedyKnapsackIterator`).
-//! - Traits `Weight` and `Value` for custom item types.
+//! - Lexicographic secondary objectives for the optimal solver (`Objective`).
+//! - Optimal value and solution for every capacity at once (`CapacityProfile`).
+//! - Reusable solver sessions for repeated capacity queries (`KnapsackSession`).
+//! - Incremental insertion and removal of items (`DynamicKnapsack`).
+//! - Online acceptance policies for streaming arrivals (`OnlineKnapsack`).
+//! - Chance-constrained knapsack with random weights (`StochasticKnapsackIterator`).
+//! - Robust knapsack under budgeted weight uncertainty (`RobustKnapsackIterator`).
+//! - Knapsack with pairwise item conflicts (`ConflictKnapsackIterator`).
+//! - Knapsack with precedence constraints between items (`PrecedenceKnapsackIterator`).
+//! - Minimum-cost covering knapsack (`CoveringKnapsackIterator`, `GreedyCoveringIterator`).
+//! - Knapsack with setup costs for item families (`FamilyKnapsackIterator`).
+//! - Quadratic knapsack with pairwise profits (`QuadraticKnapsackIterator`).
+//! - Generalized assignment of tasks to agents (`GeneralizedAssignment`).
+//! - Bin packing heuristics, exact solver, and lower bound (`BinPacking`).
+//! - Cutting stock by column generation (`CuttingStock`).
+//! - Change-making with the fewest coins (`ChangeMaking`).
+//! - Multi-period knapsack with per-period capacities (`MultiPeriodKnapsackIterator`).
+//! - Temporal knapsack with items active during time intervals (`TemporalKnapsackIterator`).
+//! - Knapsack with signed weights and values, solved optimally or greedily (`SignedKnapsackIterator`).
+//! - Pareto front of multi-objective instances (`ParetoFrontIterator`).
+//! - Configurable tie-breaking between equally good solutions (`TieBreak`).
+//! - Traits `Weight`, `Value`, `Values`, `SignedWeight`, and `SignedValue` for custom item types.
 //! - Extension traits `ToKnapsackIterator` and `ToGreedyKnapsackIterator` for easy solver creation
 //!   from iterators.
 //!
@@ -69,14 +93,81 @@
 //!
 //! ## Modules
 //!
+//! - `bin_packing`: Contains the `BinPacking` solver and its `BinPackingMethod` algorithms.
+//! - `change_making`: Contains the `ChangeMaking` solver for the fewest-coins problem.
+//! - `conflict`: Contains the `ConflictKnapsackIterator` for items with pairwise conflicts.
+//! - `covering`: Contains the exact and greedy solvers for the covering knapsack.
+//! - `cutting_stock`: Contains the `CuttingStock` solver and the `Demand` trait.
+//! - `dynamic`: Contains the `DynamicKnapsack` supporting item insertion and removal.
+//! - `family`: Contains the `FamilyKnapsackIterator`, the `Family` trait, and `FamilySetup`.
+//! - `gap`: Contains the `GeneralizedAssignment` solver and the `Assignable` trait.
 //! - `greedy`: Contains the `GreedyKnapsackIterator` for an approximate solution.
-//! - `optimal`: Contains the `KnapsackIterator` for the optimal dynamic programming solution.
-//! - `traits`: Contains the `Weight`, `Value`, `ToKnapsackIterator`, and `ToGreedyKnapsackIterator` traits.
+//! - `multi_period`: Contains the `MultiPeriodKnapsackIterator` and the `Periods` trait.
+//! - `objective`: Contains the `Objective` criteria optimized after total value.
+//! - `online`: Contains the `OnlineKnapsack` acceptor and its `OnlineKnapsackIterator` adapter.
+//! - `optimal`: Contains the `KnapsackIterator` for the optimal dynamic programming solution
+//!   along with the `AllOptimalSolutionsIterator` and `KBestKnapsackIterator` enumerators.
+//! - `pareto`: Contains the `ParetoFrontIterator` for multi-objective instances.
+//! - `precedence`: Contains the `PrecedenceKnapsackIterator` for items depending on others.
+//! - `profile`: Contains the `CapacityProfile` answering queries for any capacity.
+//! - `quadratic`: Contains the `QuadraticKnapsackIterator` and its `QuadraticMethod` algorithms.
+//! - `robust`: Contains the `RobustKnapsackIterator`, the `WeightDeviation` trait, and `worst_case_weight`.
+//! - `session`: Contains `KnapsackSession`, the `CapacityProfile` used for repeated queries.
+//! - `signed`: Contains the `SignedKnapsackIterator` for negative weights and values and its `SignedMethod` algorithms.
+//! - `stochastic`: Contains the `StochasticKnapsackIterator` for random item weights.
+//! - `temporal`: Contains the `TemporalKnapsackIterator`, the `Interval` trait, and `peak_usage`.
+//! - `tie_break`: Contains the `TieBreak` policies shared by the optimal and greedy solvers.
+//! - `traits`: Contains the `Weight`, `Value`, `Values`, `SignedWeight`, `SignedValue`, `ToKnapsackIterator`,
+//!   and `ToGreedyKnapsackIterator` traits.
 
+pub mod bin_packing;
+pub mod change_making;
+pub mod conflict;
+pub mod covering;
+pub mod cutting_stock;
+pub mod dynamic;
+pub mod family;
+pub mod gap;
 pub mod greedy;
+pub mod multi_period;
+pub mod objective;
+pub mod online;
 pub mod optimal;
+pub mod pareto;
+pub mod precedence;
+pub mod profile;
+pub mod quadratic;
+pub mod robust;
+pub mod session;
+pub mod signed;
+pub mod stochastic;
+pub mod temporal;
+pub mod tie_break;
 pub mod traits;
 
+pub use bin_packing::{BinPacking, BinPackingError, BinPackingMethod, Packing};
+pub use change_making::ChangeMaking;
+pub use conflict::{ConflictError, ConflictKnapsackIterator};
+pub use covering::{CoveringKnapsackIterator, GreedyCoveringIterator};
+pub use cutting_stock::{CuttingPattern, CuttingPlan, CuttingStock, CuttingStockError, Demand};
+pub use dynamic::DynamicKnapsack;
+pub use family::{Family, FamilyKnapsackIterator, FamilySetup};
+pub use gap::{Assignable, Assignment, GeneralizedAssignment};
 pub use greedy::GreedyKnapsackIterator;
-pub use optimal::KnapsackIterator;
-pub use traits::{ToGreedyKnapsackIterator, ToKnapsackIterator, Value, Weight};
+pub use multi_period::{MultiPeriodError, MultiPeriodKnapsackIterator, Periods};
+pub use objective::Objective;
+pub use online::{OnlineError, OnlineKnapsack, OnlineKnapsackIterator, OnlinePolicy};
+pub use optimal::{AllOptimalSolutionsIterator, KBestKnapsackIterator, KnapsackIterator};
+pub use pareto::{ParetoFrontIterator, ParetoSolution, Sense};
+pub use precedence::{PrecedenceError, PrecedenceKnapsackIterator};
+pub use profile::CapacityProfile;
+pub use quadratic::{QuadraticError, QuadraticKnapsackIterator, QuadraticMethod};
+pub use robust::{RobustKnapsackIterator, WeightDeviation};
+pub use session::KnapsackSession;
+pub use signed::{SignedKnapsackIterator, SignedMethod};
+pub use stochastic::{StochasticKnapsackIterator, StochasticWeight, WeightDistribution};
+pub use temporal::{Interval, TemporalKnapsackIterator, TemporalMethod};
+pub use tie_break::TieBreak;
+pub use traits::{
+    SignedValue, SignedWeight, ToGreedyKnapsackIterator, ToKnapsackIterator, Value, Values, Weight,
+};
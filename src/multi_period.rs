@@ -0,0 +1,402 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::optimal::{IndexedEntry, KnapsackIterator};
+use crate::traits::{Value, Weight};
+
+/// An error in the items given to `MultiPeriodKnapsackIterator`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MultiPeriodError {
+    /// An item spans a period that has no capacity.
+    PeriodOutOfBounds {
+        /// The input index of the item.
+        index: usize,
+        /// The period spanned by the item.
+        period: usize,
+    },
+}
+
+impl fmt::Display for MultiPeriodError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MultiPeriodError::PeriodOutOfBounds { index, period } => {
+                write!(
+                    f,
+                    "item {index} spans period {period}, which is out of bounds"
+                )
+            }
+        }
+    }
+}
+
+impl Error for MultiPeriodError {}
+
+/// Defines the behavior for items that span several periods.
+pub trait Periods {
+    /// Returns the periods in which the item consumes its weight.
+    ///
+    /// Repeated periods are counted once.
+    fn periods(&self) -> Vec<usize>;
+}
+
+/// An iterator that yields items of a multi-period knapsack solution.
+///
+/// Every period has its own capacity, and each item consumes its weight in
+/// every period it spans (see `Periods`). A selection is feasible if it fits
+/// within the capacity of every period, and its total value is maximized.
+///
+/// Instances are solved exactly by dynamic programming over the vector of
+/// remaining capacities when the table size, the number of states (the
+/// product of `capacity + 1` over the periods) times one more than the number
+/// of items, does not exceed `max_table_size`. Larger instances are
+/// solved heuristically with the single-period `KnapsackIterator`: the
+/// periods are aggregated into one constraint with integer multipliers, the
+/// aggregated solution is repaired by dropping items from overloaded periods
+/// and then filled with items that still fit, and the multipliers of
+/// overloaded periods are increased for the next round.
+///
+/// # Examples
+///
+/// ```
+/// use knap::multi_period::{MultiPeriodKnapsackIterator, Periods};
+/// use knap::traits::{Value, Weight};
+///
+/// #[derive(Clone, Debug, PartialEq)]
+/// struct Project {
+///     id: char,
+///     budget: usize,
+///     quarters: Vec<usize>,
+///     value: usize,
+/// }
+///
+/// impl Weight for Project {
+///     fn weight(&self) -> usize {
+///         self.budget
+///     }
+/// }
+///
+/// impl Value for Project {
+///     fn value(&self) -> usize {
+///         self.value
+///     }
+/// }
+///
+/// impl Periods for Project {
+///     fn periods(&self) -> Vec<usize> {
+///         self.quarters.clone()
+///     }
+/// }
+///
+/// let projects = vec![
+///     Project { id: 'A', budget: 3, quarters: vec![0, 1], value: 10 },
+///     Project { id: 'B', budget: 2, quarters: vec![0], value: 6 },
+///     Project { id: 'C', budget: 2, quarters: vec![1], value: 6 },
+/// ];
+///
+/// // A does not fit next to B or C, which fit together.
+/// let ids: Vec<char> = MultiPeriodKnapsackIterator::new(projects, [4, 4])
+///     .unwrap()
+///     .map(|project| project.id)
+///     .collect();
+/// assert_eq!(ids, vec!['B', 'C']);
+/// ```
+#[derive(Debug)]
+pub struct MultiPeriodKnapsackIterator<T>
+where
+    T: Weight + Value + Periods + Clone,
+{
+    items: Vec<T>,
+    capacities: Vec<usize>,
+    max_table_size: usize,
+    rounds: usize,
+    solution_items: Vec<T>,
+    current_index: usize,
+    // Ensures the solution is computed only once.
+    computed: bool,
+}
+
+impl<T> MultiPeriodKnapsackIterator<T>
+where
+    T: Weight + Value + Periods + Clone,
+{
+    /// Creates a new `MultiPeriodKnapsackIterator`.
+    ///
+    /// # Arguments
+    ///
+    /// * `input_items`: An iterator over items that implement `Weight`, `Value`, `Periods`, and `Clone`.
+    /// * `capacities`: The capacity of every period; periods are numbered in this order.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an item spans a period out of bounds.
+    pub fn new(
+        input_items: impl IntoIterator<Item = T>,
+        capacities: impl IntoIterator<Item = usize>,
+    ) -> Result<Self, MultiPeriodError> {
+        let items: Vec<T> = input_items.into_iter().collect();
+        let capacities: Vec<usize> = capacities.into_iter().collect();
+        for (index, item) in items.iter().enumerate() {
+            if let Some(period) = item.periods().into_iter().find(|&p| p >= capacities.len()) {
+                return Err(MultiPeriodError::PeriodOutOfBounds { index, period });
+            }
+        }
+        Ok(MultiPeriodKnapsackIterator {
+            items,
+            capacities,
+            max_table_size: 1 << 22,
+            rounds: 10,
+            solution_items: Vec::new(),
+            current_index: 0,
+            computed: false,
+        })
+    }
+
+    /// Sets the largest table size solved exactly. Defaults to 2^22.
+    ///
+    /// The exact solver stores one word per state and one bit per state and
+    /// item, so its memory grows with the table size: the default keeps it
+    /// within about 32 MiB.
+    pub fn with_max_table_size(mut self, max_table_size: usize) -> Self {
+        self.max_table_size = max_table_size;
+        self
+    }
+
+    /// Sets the number of aggregation rounds of the heuristic. Defaults to 10.
+    pub fn with_rounds(mut self, rounds: usize) -> Self {
+        self.rounds = rounds;
+        self
+    }
+
+    fn compute_solution(&mut self) {
+        let periods: Vec<Vec<usize>> = self
+            .items
+            .iter()
+            .map(|item| {
+                let mut periods = item.periods();
+                periods.sort_unstable();
+                periods.dedup();
+                periods
+            })
+            .collect();
+        let weights: Vec<usize> = self.items.iter().map(Weight::weight).collect();
+        let values: Vec<usize> = self.items.iter().map(Value::value).collect();
+        // Items too heavy for one of their periods can never be selected.
+        let candidates: Vec<usize> = (0..self.items.len())
+            .filter(|&idx| {
+                periods[idx]
+                    .iter()
+                    .all(|&p| weights[idx] <= self.capacities[p])
+            })
+            .collect();
+
+        let states = self
+            .capacities
+            .iter()
+            .try_fold(1usize, |states, &capacity| states.checked_mul(capacity + 1));
+        let table_size = states.and_then(|states| states.checked_mul(candidates.len() + 1));
+        let instance = Instance {
+            weights: &weights,
+            values: &values,
+            periods: &periods,
+            capacities: &self.capacities,
+        };
+        let mut selected = match (states, table_size) {
+            (Some(states), Some(size)) if size <= self.max_table_size => {
+                instance.exact(&candidates, states)
+            }
+            _ => instance.heuristic(&candidates, self.rounds),
+        };
+        selected.sort_unstable();
+        self.solution_items = selected
+            .into_iter()
+            .map(|idx| self.items[idx].clone())
+            .collect();
+    }
+}
+
+impl<T> Iterator for MultiPeriodKnapsackIterator<T>
+where
+    T: Weight + Value + Periods + Clone,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.computed {
+            self.compute_solution();
+            self.computed = true;
+        }
+
+        if self.current_index < self.solution_items.len() {
+            let item = self.solution_items[self.current_index].clone();
+            self.current_index += 1;
+            Some(item)
+        } else {
+            None
+        }
+    }
+}
+
+struct Instance<'a> {
+    weights: &'a [usize],
+    values: &'a [usize],
+    periods: &'a [Vec<usize>],
+    capacities: &'a [usize],
+}
+
+impl Instance<'_> {
+    // Dynamic programming over the vector of used capacities, encoded in a
+    // mixed radix with `capacity + 1` values per period.
+    fn exact(&self, candidates: &[usize], states: usize) -> Vec<usize> {
+        let mut strides = Vec::with_capacity(self.capacities.len());
+        let mut stride = 1;
+        for &capacity in self.capacities {
+            strides.push(stride);
+            stride *= capacity + 1;
+        }
+
+        // `best[s]` is the best value within the capacities encoded by `s`;
+        // `taken[k]` has the bits of the states where candidate `k` is taken.
+        let mut best = vec![0usize; states];
+        let mut taken = vec![vec![0u64; states.div_ceil(64)]; candidates.len()];
+        for (k, &idx) in candidates.iter().enumerate() {
+            let weight = self.weights[idx];
+            let shift: usize = self.periods[idx].iter().map(|&p| weight * strides[p]).sum();
+            for s in (0..states).rev() {
+                let fits = self.periods[idx]
+                    .iter()
+                    .all(|&p| (s / strides[p]) % (self.capacities[p] + 1) >= weight);
+                if fits && best[s - shift] + self.values[idx] > best[s] {
+                    best[s] = best[s - shift] + self.values[idx];
+                    taken[k][s / 64] |= 1 << (s % 64);
+                }
+            }
+        }
+
+        let mut selected = Vec::new();
+        let mut s = states - 1;
+        for (k, &idx) in candidates.iter().enumerate().rev() {
+            if taken[k][s / 64] & (1 << (s % 64)) != 0 {
+                selected.push(idx);
+                s -= self.periods[idx]
+                    .iter()
+                    .map(|&p| self.weights[idx] * strides[p])
+                    .sum::<usize>();
+            }
+        }
+        selected
+    }
+
+    fn heuristic(&self, candidates: &[usize], rounds: usize) -> Vec<usize> {
+        let mut multipliers = vec![1usize; self.capacities.len()];
+        let mut best: Vec<usize> = Vec::new();
+        let mut best_value = 0;
+        for _ in 0..rounds.max(1) {
+            let entries: Vec<IndexedEntry> = candidates
+                .iter()
+                .map(|&idx| IndexedEntry {
+                    index: idx,
+                    weight: self.periods[idx]
+                        .iter()
+                        .map(|&p| multipliers[p] * self.weights[idx])
+                        .sum(),
+                    value: self.values[idx],
+                })
+                .collect();
+            let capacity = self
+                .capacities
+                .iter()
+                .zip(&multipliers)
+                .map(|(capacity, multiplier)| capacity * multiplier)
+                .sum();
+            let mut selected: Vec<usize> = KnapsackIterator::new(entries, capacity)
+                .map(|entry| entry.index)
+                .collect();
+
+            let overloaded = self.overloaded(&selected);
+            self.repair(&mut selected);
+            self.fill(&mut selected, candidates);
+            let value: usize = selected.iter().map(|&idx| self.values[idx]).sum();
+            if value > best_value {
+                best_value = value;
+                best = selected;
+            }
+            if overloaded.iter().all(|&over| !over) {
+                break;
+            }
+            for (multiplier, over) in multipliers.iter_mut().zip(overloaded) {
+                if over {
+                    *multiplier += 1;
+                }
+            }
+        }
+        best
+    }
+
+    fn usage(&self, selected: &[usize]) -> Vec<usize> {
+        let mut usage = vec![0; self.capacities.len()];
+        for &idx in selected {
+            for &p in &self.periods[idx] {
+                usage[p] += self.weights[idx];
+            }
+        }
+        usage
+    }
+
+    fn overloaded(&self, selected: &[usize]) -> Vec<bool> {
+        self.usage(selected)
+            .iter()
+            .zip(self.capacities)
+            .map(|(used, capacity)| used > capacity)
+            .collect()
+    }
+
+    // Drops the item with the least value per unit of overloading weight
+    // until every period fits.
+    fn repair(&self, selected: &mut Vec<usize>) {
+        loop {
+            let overloaded = self.overloaded(selected);
+            let worst = selected
+                .iter()
+                .enumerate()
+                .filter_map(|(position, &idx)| {
+                    let excess = self.periods[idx].iter().filter(|&&p| overloaded[p]).count()
+                        * self.weights[idx];
+                    (excess > 0).then(|| (position, self.values[idx] as f64 / excess as f64))
+                })
+                .min_by(|a, b| a.1.total_cmp(&b.1).then(b.0.cmp(&a.0)));
+            match worst {
+                Some((position, _)) => {
+                    selected.remove(position);
+                }
+                None => break,
+            }
+        }
+    }
+
+    // Adds the remaining items by decreasing value per unit of total weight
+    // whenever they fit in all their periods.
+    fn fill(&self, selected: &mut Vec<usize>, candidates: &[usize]) {
+        let mut usage = self.usage(selected);
+        let density = |idx: usize| match self.periods[idx].len() * self.weights[idx] {
+            0 => f64::MAX,
+            weight => self.values[idx] as f64 / weight as f64,
+        };
+        let mut order: Vec<usize> = candidates
+            .iter()
+            .copied()
+            .filter(|idx| !selected.contains(idx))
+            .collect();
+        order.sort_by(|&a, &b| density(b).total_cmp(&density(a)).then(a.cmp(&b)));
+        for idx in order {
+            let fits = self.periods[idx]
+                .iter()
+                .all(|&p| usage[p] + self.weights[idx] <= self.capacities[p]);
+            if fits {
+                for &p in &self.periods[idx] {
+                    usage[p] += self.weights[idx];
+                }
+                selected.push(idx);
+            }
+        }
+    }
+}
@@ -0,0 +1,95 @@
+use std::fmt;
+
+use crate::traits::Weight;
+
+/// A secondary criterion optimized among all maximum-value solutions.
+///
+/// Objectives are given to `KnapsackIterator::with_objectives` in priority
+/// order: the solver first maximizes total value, then optimizes the first
+/// objective among all value-optimal packings, then the second objective among
+/// those, and so on. Every criterion is optimized exactly by the dynamic
+/// programming solver.
+///
+/// # Examples
+///
+/// ```
+/// use knap::traits::{Value, Weight};
+/// use knap::{KnapsackIterator, Objective};
+///
+/// #[derive(Clone, Debug, PartialEq)]
+/// struct Item {
+///     id: char,
+///     weight: usize,
+///     value: usize,
+/// }
+///
+/// impl Weight for Item {
+///     fn weight(&self) -> usize {
+///         self.weight
+///     }
+/// }
+///
+/// impl Value for Item {
+///     fn value(&self) -> usize {
+///         self.value
+///     }
+/// }
+///
+/// let items = vec![
+///     Item { id: 'A', weight: 1, value: 2 },
+///     Item { id: 'B', weight: 1, value: 2 },
+///     Item { id: 'C', weight: 2, value: 4 },
+///     Item { id: 'D', weight: 3, value: 4 },
+/// ];
+///
+/// let ids: Vec<char> = KnapsackIterator::new(items, 2)
+///     .with_objectives([Objective::MinimizeWeight, Objective::MinimizeCount])
+///     .map(|item| item.id)
+///     .collect();
+///
+/// assert_eq!(ids, vec!['C']);
+/// ```
+pub enum Objective<T> {
+    /// Minimize the total weight of the selected items.
+    MinimizeWeight,
+    /// Minimize the number of selected items.
+    MinimizeCount,
+    /// Minimize the sum of the given per-item score.
+    Minimize(fn(&T) -> usize),
+    /// Maximize the sum of the given per-item score.
+    Maximize(fn(&T) -> usize),
+}
+
+impl<T> Objective<T>
+where
+    T: Weight,
+{
+    // Signed contribution of `item` to this objective, so that larger sums are better.
+    pub(crate) fn contribution(&self, item: &T) -> i64 {
+        match self {
+            Objective::MinimizeWeight => -(item.weight() as i64),
+            Objective::MinimizeCount => -1,
+            Objective::Minimize(score) => -(score(item) as i64),
+            Objective::Maximize(score) => score(item) as i64,
+        }
+    }
+}
+
+impl<T> Clone for Objective<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Objective<T> {}
+
+impl<T> fmt::Debug for Objective<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Objective::MinimizeWeight => write!(f, "MinimizeWeight"),
+            Objective::MinimizeCount => write!(f, "MinimizeCount"),
+            Objective::Minimize(_) => write!(f, "Minimize(..)"),
+            Objective::Maximize(_) => write!(f, "Maximize(..)"),
+        }
+    }
+}
@@ -0,0 +1,294 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::traits::{Value, Weight};
+
+/// An error in the policy given to `OnlineKnapsack`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OnlineError {
+    /// The density of `OnlinePolicy::FixedThreshold` is negative or not finite.
+    InvalidThreshold(f64),
+    /// The bounds of `OnlinePolicy::Threshold` are not finite with `0 < lower <= upper`.
+    InvalidThresholdBounds {
+        /// The lower bound on item densities.
+        lower: f64,
+        /// The upper bound on item densities.
+        upper: f64,
+    },
+}
+
+impl fmt::Display for OnlineError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OnlineError::InvalidThreshold(density) => {
+                write!(f, "threshold density {density} is negative or not finite")
+            }
+            OnlineError::InvalidThresholdBounds { lower, upper } => {
+                write!(
+                    f,
+                    "density bounds {lower} and {upper} do not satisfy 0 < lower <= upper"
+                )
+            }
+        }
+    }
+}
+
+impl Error for OnlineError {}
+
+/// The rule used by `OnlineKnapsack` to accept or reject arriving items.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OnlinePolicy {
+    /// Accept every item that fits in the remaining capacity.
+    Greedy,
+    /// Accept items that fit and whose value-to-weight ratio is at least the given density.
+    FixedThreshold(f64),
+    /// The Zhou–Chakrabarty–Lukose threshold policy.
+    ///
+    /// Requires bounds `lower <= value / weight <= upper` on the densities of
+    /// arriving items. An item is accepted if it fits and its density is at
+    /// least `psi(z)`, where `z` is the fraction of capacity already used:
+    /// `psi(z) = lower` for `z <= 1 / (1 + ln(upper / lower))` and
+    /// `psi(z) = (upper * e / lower)^z * (lower / e)` above it. When item
+    /// weights are small compared to the capacity, this policy is
+    /// `(1 + ln(upper / lower))`-competitive.
+    Threshold { lower: f64, upper: f64 },
+}
+
+impl OnlinePolicy {
+    // Returns the minimum density accepted when `used` of `capacity` is taken.
+    fn threshold(&self, used: usize, capacity: usize) -> f64 {
+        match *self {
+            OnlinePolicy::Greedy => 0.0,
+            OnlinePolicy::FixedThreshold(density) => density,
+            OnlinePolicy::Threshold { lower, upper } => {
+                let z = if capacity == 0 {
+                    1.0
+                } else {
+                    used as f64 / capacity as f64
+                };
+                let breakpoint = 1.0 / (1.0 + (upper / lower).ln());
+                if z <= breakpoint {
+                    lower
+                } else {
+                    (upper * std::f64::consts::E / lower).powf(z) * (lower / std::f64::consts::E)
+                }
+            }
+        }
+    }
+}
+
+/// A stateful acceptor for the online knapsack problem.
+///
+/// Items are offered one at a time and must be accepted or rejected
+/// immediately, without knowledge of future arrivals. Accepted items stay in
+/// the knapsack. This complements `GreedyKnapsackIterator`, which sees every
+/// item before deciding.
+///
+/// # Examples
+///
+/// ```
+/// use knap::online::{OnlineKnapsack, OnlinePolicy};
+/// use knap::traits::{Value, Weight};
+///
+/// #[derive(Clone, Debug, PartialEq)]
+/// struct Item {
+///     weight: usize,
+///     value: usize,
+/// }
+///
+/// impl Weight for Item {
+///     fn weight(&self) -> usize {
+///         self.weight
+///     }
+/// }
+///
+/// impl Value for Item {
+///     fn value(&self) -> usize {
+///         self.value
+///     }
+/// }
+///
+/// let mut knapsack = OnlineKnapsack::new(10, OnlinePolicy::FixedThreshold(2.0)).unwrap();
+///
+/// assert!(knapsack.offer(Item { weight: 4, value: 12 }));
+/// assert!(!knapsack.offer(Item { weight: 4, value: 4 })); // Density 1 is too low.
+/// assert!(!knapsack.offer(Item { weight: 7, value: 70 })); // Does not fit.
+/// assert!(knapsack.offer(Item { weight: 6, value: 18 }));
+///
+/// assert_eq!(knapsack.used_capacity(), 10);
+/// assert_eq!(knapsack.total_value(), 30);
+/// ```
+#[derive(Debug)]
+pub struct OnlineKnapsack<T>
+where
+    T: Weight + Value,
+{
+    capacity: usize,
+    policy: OnlinePolicy,
+    accepted: Vec<T>,
+    used_capacity: usize,
+    total_value: usize,
+}
+
+impl<T> OnlineKnapsack<T>
+where
+    T: Weight + Value,
+{
+    /// Creates an empty `OnlineKnapsack`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the density of `OnlinePolicy::FixedThreshold` is
+    /// negative or not finite, or if the bounds of `OnlinePolicy::Threshold`
+    /// are not finite with `0 < lower <= upper`.
+    pub fn new(capacity: usize, policy: OnlinePolicy) -> Result<Self, OnlineError> {
+        match policy {
+            OnlinePolicy::Greedy => {}
+            OnlinePolicy::FixedThreshold(density) => {
+                if !(density.is_finite() && density >= 0.0) {
+                    return Err(OnlineError::InvalidThreshold(density));
+                }
+            }
+            OnlinePolicy::Threshold { lower, upper } => {
+                if !(upper.is_finite() && lower > 0.0 && lower <= upper) {
+                    return Err(OnlineError::InvalidThresholdBounds { lower, upper });
+                }
+            }
+        }
+        Ok(OnlineKnapsack {
+            capacity,
+            policy,
+            accepted: Vec::new(),
+            used_capacity: 0,
+            total_value: 0,
+        })
+    }
+
+    /// Decides whether to accept `item`, keeping it if so.
+    ///
+    /// Items with zero weight are always accepted.
+    pub fn offer(&mut self, item: T) -> bool {
+        let item_weight = item.weight();
+        if item_weight > self.capacity - self.used_capacity {
+            return false;
+        }
+        if item_weight > 0 {
+            let density = item.value() as f64 / item_weight as f64;
+            if density < self.policy.threshold(self.used_capacity, self.capacity) {
+                return false;
+            }
+        }
+        self.used_capacity += item_weight;
+        self.total_value += item.value();
+        self.accepted.push(item);
+        true
+    }
+
+    /// Returns the accepted items, in arrival order.
+    pub fn accepted(&self) -> &[T] {
+        &self.accepted
+    }
+
+    /// Returns the total weight of the accepted items.
+    pub fn used_capacity(&self) -> usize {
+        self.used_capacity
+    }
+
+    /// Returns the capacity still available.
+    pub fn remaining_capacity(&self) -> usize {
+        self.capacity - self.used_capacity
+    }
+
+    /// Returns the total value of the accepted items.
+    pub fn total_value(&self) -> usize {
+        self.total_value
+    }
+}
+
+/// An iterator that feeds a stream of items to an `OnlineKnapsack` and yields
+/// the accepted ones as they arrive.
+///
+/// # Examples
+///
+/// ```
+/// use knap::online::{OnlineKnapsackIterator, OnlinePolicy};
+/// use knap::traits::{Value, Weight};
+///
+/// #[derive(Clone, Debug, PartialEq)]
+/// struct Item {
+///     weight: usize,
+///     value: usize,
+/// }
+///
+/// impl Weight for Item {
+///     fn weight(&self) -> usize {
+///         self.weight
+///     }
+/// }
+///
+/// impl Value for Item {
+///     fn value(&self) -> usize {
+///         self.value
+///     }
+/// }
+///
+/// let arrivals = vec![
+///     Item { weight: 3, value: 3 },
+///     Item { weight: 3, value: 30 },
+///     Item { weight: 3, value: 6 },
+/// ];
+///
+/// let accepted: Vec<Item> = OnlineKnapsackIterator::new(arrivals, 6, OnlinePolicy::Greedy)
+///     .unwrap()
+///     .collect();
+/// assert_eq!(accepted, vec![Item { weight: 3, value: 3 }, Item { weight: 3, value: 30 }]);
+/// ```
+#[derive(Debug)]
+pub struct OnlineKnapsackIterator<I>
+where
+    I: Iterator,
+    I::Item: Weight + Value + Clone,
+{
+    arrivals: I,
+    knapsack: OnlineKnapsack<I::Item>,
+}
+
+impl<I> OnlineKnapsackIterator<I>
+where
+    I: Iterator,
+    I::Item: Weight + Value + Clone,
+{
+    /// Creates a new `OnlineKnapsackIterator` over a stream of arriving items.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error under the same conditions as `OnlineKnapsack::new`.
+    pub fn new(
+        arrivals: impl IntoIterator<IntoIter = I>,
+        capacity: usize,
+        policy: OnlinePolicy,
+    ) -> Result<Self, OnlineError> {
+        Ok(OnlineKnapsackIterator {
+            arrivals: arrivals.into_iter(),
+            knapsack: OnlineKnapsack::new(capacity, policy)?,
+        })
+    }
+
+    /// Returns the underlying acceptor and its state so far.
+    pub fn knapsack(&self) -> &OnlineKnapsack<I::Item> {
+        &self.knapsack
+    }
+}
+
+impl<I> Iterator for OnlineKnapsackIterator<I>
+where
+    I: Iterator,
+    I::Item: Weight + Value + Clone,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let knapsack = &mut self.knapsack;
+        self.arrivals.find(|item| knapsack.offer(item.clone()))
+    }
+}
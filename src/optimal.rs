@@ -1,3 +1,10 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+use crate::covering::min_cover;
+use crate::objective::Objective;
+use crate::profile::CapacityProfile;
+use crate::tie_break::TieBreak;
 use crate::traits::{Value, Weight};
 
 #[derive(Debug)]
@@ -7,6 +14,10 @@ where
 {
     items: Vec<T>,
     capacity: usize,
+    tie_break: Option<TieBreak<T>>,
+    objectives: Vec<Objective<T>>,
+    min_items: usize,
+    max_items: Option<usize>,
     optimal_solution_items: Vec<T>,
     current_index: usize,
     // Ensures DP is run only once.
@@ -22,53 +33,204 @@ where
         KnapsackIterator {
             items,
             capacity,
+            tie_break: None,
+            objectives: Vec::new(),
+            min_items: 0,
+            max_items: None,
             optimal_solution_items: Vec::new(),
             current_index: 0,
             computed: false,
         }
     }
 
+    /// Solves the instance for every capacity from zero up to this iterator's capacity.
+    ///
+    /// See `CapacityProfile` for details.
+    pub fn capacity_profile(&self) -> CapacityProfile<T> {
+        CapacityProfile::new(self.items.clone(), self.capacity)
+    }
+
+    /// Finds the lightest subset whose total value reaches at least `target`.
+    ///
+    /// This is the inverse of the knapsack query: the weight is minimized for
+    /// a given value instead of the value for a given weight. It is solved by
+    /// dynamic programming indexed by value, capped at `target`, in
+    /// O(n * target). Returns the minimum total weight and the selected items
+    /// in input order, or `None` if no subset fitting in this iterator's
+    /// capacity reaches the target.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use knap::optimal::KnapsackIterator;
+    /// use knap::traits::{Value, Weight};
+    ///
+    /// #[derive(Clone, Debug, PartialEq)]
+    /// struct Item {
+    ///     id: char,
+    ///     weight: usize,
+    ///     value: usize,
+    /// }
+    ///
+    /// impl Weight for Item {
+    ///     fn weight(&self) -> usize {
+    ///         self.weight
+    ///     }
+    /// }
+    ///
+    /// impl Value for Item {
+    ///     fn value(&self) -> usize {
+    ///         self.value
+    ///     }
+    /// }
+    ///
+    /// let items = vec![
+    ///     Item { id: 'A', weight: 10, value: 60 },
+    ///     Item { id: 'B', weight: 20, value: 100 },
+    ///     Item { id: 'C', weight: 30, value: 120 },
+    /// ];
+    ///
+    /// let (weight, subset) = KnapsackIterator::new(items.clone(), 50)
+    ///     .min_weight_for_value(150)
+    ///     .unwrap();
+    /// assert_eq!(weight, 30);
+    /// assert_eq!(subset.iter().map(|item| item.id).collect::<Vec<_>>(), vec!['A', 'B']);
+    ///
+    /// // Reaching 230 takes all three items, which do not fit in 50.
+    /// assert_eq!(KnapsackIterator::new(items, 50).min_weight_for_value(230), None);
+    /// ```
+    pub fn min_weight_for_value(&self, target: usize) -> Option<(usize, Vec<T>)> {
+        let entries: Vec<(usize, usize)> = self
+            .items
+            .iter()
+            .map(|item| (item.value(), item.weight()))
+            .collect();
+        let (weight, indices) = min_cover(&entries, target)?;
+        if weight > self.capacity {
+            return None;
+        }
+        Some((
+            weight,
+            indices
+                .into_iter()
+                .map(|idx| self.items[idx].clone())
+                .collect(),
+        ))
+    }
+
+    /// Sets the policy used to choose among several optimal solutions.
+    ///
+    /// Without a policy, the solution is whichever one the dynamic programming
+    /// backtracking finds first. When objectives are also set, the policy only
+    /// decides between solutions that are equally good on every objective.
+    pub fn with_tie_break(mut self, tie_break: TieBreak<T>) -> Self {
+        self.tie_break = Some(tie_break);
+        self
+    }
+
+    /// Sets secondary objectives optimized, in order, among all value-optimal solutions.
+    ///
+    /// See `Objective` for details.
+    pub fn with_objectives(mut self, objectives: impl IntoIterator<Item = Objective<T>>) -> Self {
+        self.objectives = objectives.into_iter().collect();
+        self
+    }
+
+    /// Limits the solution to at most `max_items` items.
+    ///
+    /// Item limits are enforced exactly by adding a count dimension to the
+    /// dynamic programming table. When limits are set without a tie-break
+    /// policy, ties are resolved as by `TieBreak::LowestIndices`.
+    ///
+    /// The count dimension has `min(max_items, n) + 1` entries, so the table
+    /// takes O(n * capacity * max_items) memory instead of O(n * capacity).
+    pub fn with_max_items(mut self, max_items: usize) -> Self {
+        self.max_items = Some(max_items);
+        self
+    }
+
+    /// Requires the solution to contain at least `min_items` items.
+    ///
+    /// If no subset with at least `min_items` items fits, the iterator yields nothing.
+    ///
+    /// Without `with_max_items`, the count dimension has `min_items + 1`
+    /// entries, so the table takes O(n * capacity * min_items) memory. With a
+    /// maximum, the count dimension is sized by the maximum instead.
+    pub fn with_min_items(mut self, min_items: usize) -> Self {
+        self.min_items = min_items;
+        self
+    }
+
     // Computes the optimal solution using dynamic programming.
     fn compute_solution(&mut self) {
         let n = self.items.len();
+        let entries = entries(&self.items);
+        // An empty knapsack yields nothing, even when weightless items would fit.
         if n == 0 || self.capacity == 0 {
-            self.computed = true;
             return;
         }
-
-        let mut dp = vec![vec![0; self.capacity + 1]; n + 1];
-
-        for i in 1..=n {
-            let item_idx = i - 1;
-            let item_weight = self.items[item_idx].weight();
-            let item_value = self.items[item_idx].value();
-
-            for w in 0..=self.capacity {
-                let value_without_item = dp[i - 1][w];
-                if item_weight <= w {
-                    let value_with_item = dp[i - 1][w - item_weight] + item_value;
-                    dp[i][w] = value_without_item.max(value_with_item);
-                } else {
-                    dp[i][w] = value_without_item;
-                }
-            }
+        let unconstrained = self.min_items == 0 && self.max_items.is_none();
+        if self.tie_break.is_none() && self.objectives.is_empty() && unconstrained {
+            let (_, indices) = solve(&entries, self.capacity);
+            self.set_solution(indices);
+            return;
         }
 
-        let mut current_w = self.capacity;
-        let mut solution_items_temp = Vec::new();
-
-        for i in (1..=n).rev() {
-            let item_idx = i - 1;
-            let item_weight = self.items[item_idx].weight();
+        // Lexicographic keys: value first, then each objective in priority
+        // order, then the tie-break policy when it can be expressed as a sum.
+        let keys: Vec<Vec<i64>> = self
+            .items
+            .iter()
+            .map(|item| {
+                let mut key = vec![item.value() as i64];
+                key.extend(
+                    self.objectives
+                        .iter()
+                        .map(|objective| objective.contribution(item)),
+                );
+                match self.tie_break {
+                    Some(TieBreak::FewestItems) => key.push(-1),
+                    Some(TieBreak::LightestWeight) => key.push(-(item.weight() as i64)),
+                    _ => {}
+                }
+                key
+            })
+            .collect();
+        let count_limits = match self.max_items {
+            Some(max_items) => CountLimits::Between(self.min_items, max_items.min(n)),
+            None if self.min_items > n => return,
+            None => CountLimits::AtLeast(self.min_items),
+        };
+        let table = LexTable::new(&entries, &keys, self.capacity, count_limits);
 
-            if current_w >= item_weight && dp[i][current_w] != dp[i - 1][current_w] {
-                solution_items_temp.push(self.items[item_idx].clone());
-                current_w -= item_weight;
+        let indices = match self.tie_break {
+            Some(TieBreak::Custom(compare)) => {
+                let mut best: Option<(Vec<usize>, Vec<T>)> = None;
+                for indices in table.all_optimal() {
+                    let candidate: Vec<T> =
+                        indices.iter().map(|&idx| self.items[idx].clone()).collect();
+                    let is_better = match &best {
+                        None => true,
+                        Some((best_indices, best_items)) => compare(&candidate, best_items)
+                            .then_with(|| indices.cmp(best_indices))
+                            .is_lt(),
+                    };
+                    if is_better {
+                        best = Some((indices, candidate));
+                    }
+                }
+                best.map(|(indices, _)| indices)
             }
-        }
+            _ => table.lowest_indices(),
+        };
+        self.set_solution(indices.unwrap_or_default());
+    }
 
-        solution_items_temp.reverse();
-        self.optimal_solution_items = solution_items_temp;
+    fn set_solution(&mut self, indices: Vec<usize>) {
+        self.optimal_solution_items = indices
+            .into_iter()
+            .map(|idx| self.items[idx].clone())
+            .collect();
     }
 }
 
@@ -93,3 +255,668 @@ where
         }
     }
 }
+
+/// An iterator over every optimal solution of a 0/1 knapsack instance.
+///
+/// Each call to `next` yields one subset of the input items (in input order)
+/// whose total value equals the optimum for the given capacity. Subsets are
+/// produced lazily by walking the dynamic programming table and following both
+/// branches whenever an item can be either skipped or taken without losing
+/// optimality, so every optimal subset is yielded exactly once.
+///
+/// For any positive capacity, the first subset yielded is the same one
+/// returned by `KnapsackIterator`.
+///
+/// # Examples
+///
+/// ```
+/// use knap::optimal::AllOptimalSolutionsIterator;
+/// use knap::traits::{Value, Weight};
+///
+/// #[derive(Clone, Debug, PartialEq)]
+/// struct Item {
+///     id: char,
+///     weight: usize,
+///     value: usize,
+/// }
+///
+/// impl Weight for Item {
+///     fn weight(&self) -> usize {
+///         self.weight
+///     }
+/// }
+///
+/// impl Value for Item {
+///     fn value(&self) -> usize {
+///         self.value
+///     }
+/// }
+///
+/// let items = vec![
+///     Item { id: 'A', weight: 2, value: 3 },
+///     Item { id: 'B', weight: 2, value: 3 },
+///     Item { id: 'C', weight: 4, value: 6 },
+/// ];
+///
+/// let solutions: Vec<Vec<char>> = AllOptimalSolutionsIterator::new(items, 4)
+///     .map(|solution| solution.into_iter().map(|item| item.id).collect())
+///     .collect();
+///
+/// assert_eq!(solutions, vec![vec!['A', 'B'], vec!['C']]);
+/// ```
+#[derive(Debug)]
+pub struct AllOptimalSolutionsIterator<T>
+where
+    T: Weight + Value + Clone,
+{
+    items: Vec<T>,
+    walker: OptimalWalker,
+}
+
+impl<T> AllOptimalSolutionsIterator<T>
+where
+    T: Weight + Value + Clone,
+{
+    /// Creates a new `AllOptimalSolutionsIterator`.
+    ///
+    /// # Arguments
+    ///
+    /// * `input_items`: An iterator over items that implement `Weight`, `Value`, and `Clone`.
+    /// * `capacity`: The maximum capacity of the knapsack.
+    pub fn new(input_items: impl IntoIterator<Item = T>, capacity: usize) -> Self {
+        let items: Vec<T> = input_items.into_iter().collect();
+        let walker = OptimalWalker::new(entries(&items), capacity);
+
+        AllOptimalSolutionsIterator { items, walker }
+    }
+
+    /// Returns the optimal total value shared by every yielded solution.
+    pub fn optimal_value(&self) -> usize {
+        self.walker.dp[self.items.len()]
+            .last()
+            .copied()
+            .unwrap_or(0)
+    }
+}
+
+impl<T> Iterator for AllOptimalSolutionsIterator<T>
+where
+    T: Weight + Value + Clone,
+{
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let indices = self.walker.next_indices()?;
+        Some(
+            indices
+                .into_iter()
+                .map(|idx| self.items[idx].clone())
+                .collect(),
+        )
+    }
+}
+
+// Depth-first walk over the DP table yielding the indices of every optimal subset.
+#[derive(Debug)]
+struct OptimalWalker {
+    entries: Vec<(usize, usize)>,
+    dp: Vec<Vec<usize>>,
+    // Partial solutions still to be explored: (entries left to decide,
+    // remaining capacity, indices taken so far in decreasing order).
+    stack: Vec<(usize, usize, Vec<usize>)>,
+}
+
+impl OptimalWalker {
+    fn new(entries: Vec<(usize, usize)>, capacity: usize) -> Self {
+        let dp = dp_table(&entries, capacity);
+        let stack = vec![(entries.len(), capacity, Vec::new())];
+        OptimalWalker { entries, dp, stack }
+    }
+
+    fn next_indices(&mut self) -> Option<Vec<usize>> {
+        while let Some((i, w, mut taken)) = self.stack.pop() {
+            if i == 0 {
+                taken.reverse();
+                return Some(taken);
+            }
+
+            let (item_weight, item_value) = self.entries[i - 1];
+            let target = self.dp[i][w];
+
+            // Pushed first so that skipping is explored first, matching the
+            // backtracking order of `KnapsackIterator`.
+            if item_weight <= w && self.dp[i - 1][w - item_weight] + item_value == target {
+                let mut with_item = taken.clone();
+                with_item.push(i - 1);
+                self.stack.push((i - 1, w - item_weight, with_item));
+            }
+            if self.dp[i - 1][w] == target {
+                self.stack.push((i - 1, w, taken));
+            }
+        }
+        None
+    }
+}
+
+/// An iterator over the `k` best feasible solutions of a 0/1 knapsack instance.
+///
+/// Solutions are distinct subsets of the input items (in input order), yielded
+/// in non-increasing order of total value. For any positive capacity, the
+/// first solution is the one returned by `KnapsackIterator`; at capacity zero,
+/// it holds every weightless item with a positive value.
+///
+/// The solution space is explored with Lawler's partitioning scheme: once a
+/// solution is yielded, the remaining space is split into disjoint subproblems
+/// by fixing a prefix of its decisions and flipping the next one, and each
+/// subproblem is solved exactly with the dynamic programming solver.
+///
+/// # Examples
+///
+/// ```
+/// use knap::optimal::KBestKnapsackIterator;
+/// use knap::traits::{Value, Weight};
+///
+/// #[derive(Clone, Debug, PartialEq)]
+/// struct Item {
+///     id: char,
+///     weight: usize,
+///     value: usize,
+/// }
+///
+/// impl Weight for Item {
+///     fn weight(&self) -> usize {
+///         self.weight
+///     }
+/// }
+///
+/// impl Value for Item {
+///     fn value(&self) -> usize {
+///         self.value
+///     }
+/// }
+///
+/// let items = vec![
+///     Item { id: 'A', weight: 10, value: 60 },
+///     Item { id: 'B', weight: 20, value: 100 },
+///     Item { id: 'C', weight: 30, value: 120 },
+/// ];
+///
+/// let values: Vec<usize> = KBestKnapsackIterator::new(items, 50, 3)
+///     .map(|solution| solution.iter().map(|item| item.value).sum())
+///     .collect();
+///
+/// assert_eq!(values, vec![220, 180, 160]);
+/// ```
+#[derive(Debug)]
+pub struct KBestKnapsackIterator<T>
+where
+    T: Weight + Value + Clone,
+{
+    items: Vec<T>,
+    entries: Vec<(usize, usize)>,
+    // Subproblems ordered by their best value, ties broken by creation order.
+    queue: BinaryHeap<(usize, Reverse<usize>)>,
+    subproblems: Vec<Option<Subproblem>>,
+    remaining: usize,
+}
+
+// A region of the solution space together with its best solution.
+#[derive(Debug)]
+struct Subproblem {
+    forced_in: Vec<usize>,
+    free: Vec<usize>,
+    capacity: usize,
+    // Indices into `free` selected by the best solution of this region.
+    chosen: Vec<bool>,
+}
+
+impl<T> KBestKnapsackIterator<T>
+where
+    T: Weight + Value + Clone,
+{
+    /// Creates a new `KBestKnapsackIterator`.
+    ///
+    /// # Arguments
+    ///
+    /// * `input_items`: An iterator over items that implement `Weight`, `Value`, and `Clone`.
+    /// * `capacity`: The maximum capacity of the knapsack.
+    /// * `k`: The maximum number of solutions to yield.
+    pub fn new(input_items: impl IntoIterator<Item = T>, capacity: usize, k: usize) -> Self {
+        let items: Vec<T> = input_items.into_iter().collect();
+        let entries = entries(&items);
+        let mut iter = KBestKnapsackIterator {
+            items,
+            entries,
+            queue: BinaryHeap::new(),
+            subproblems: Vec::new(),
+            remaining: k,
+        };
+        let free = (0..iter.items.len()).collect();
+        iter.push_subproblem(Vec::new(), free, capacity);
+        iter
+    }
+
+    fn push_subproblem(&mut self, forced_in: Vec<usize>, free: Vec<usize>, capacity: usize) {
+        let free_entries: Vec<(usize, usize)> = free.iter().map(|&idx| self.entries[idx]).collect();
+        let (free_value, selected) = solve(&free_entries, capacity);
+        let forced_value: usize = forced_in.iter().map(|&idx| self.entries[idx].1).sum();
+
+        let mut chosen = vec![false; free.len()];
+        for position in selected {
+            chosen[position] = true;
+        }
+
+        let id = self.subproblems.len();
+        self.subproblems.push(Some(Subproblem {
+            forced_in,
+            free,
+            capacity,
+            chosen,
+        }));
+        self.queue.push((forced_value + free_value, Reverse(id)));
+    }
+}
+
+impl<T> Iterator for KBestKnapsackIterator<T>
+where
+    T: Weight + Value + Clone,
+{
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let (_, Reverse(id)) = self.queue.pop()?;
+        let subproblem = self.subproblems[id].take()?;
+        self.remaining -= 1;
+
+        let mut solution: Vec<usize> = subproblem.forced_in.clone();
+        solution.extend(
+            subproblem
+                .free
+                .iter()
+                .zip(&subproblem.chosen)
+                .filter(|(_, &chosen)| chosen)
+                .map(|(&idx, _)| idx),
+        );
+        solution.sort_unstable();
+
+        // Partition the rest of the region: child `j` agrees with this solution
+        // on the first `j` free items and disagrees on the next one.
+        if self.remaining > 0 {
+            let mut forced_in = subproblem.forced_in;
+            let mut capacity = subproblem.capacity;
+            for (j, &idx) in subproblem.free.iter().enumerate() {
+                let weight = self.entries[idx].0;
+                let rest = subproblem.free[j + 1..].to_vec();
+                if subproblem.chosen[j] {
+                    self.push_subproblem(forced_in.clone(), rest, capacity);
+                    forced_in.push(idx);
+                    capacity -= weight;
+                } else if weight <= capacity {
+                    let mut flipped = forced_in.clone();
+                    flipped.push(idx);
+                    self.push_subproblem(flipped, rest, capacity - weight);
+                }
+            }
+        }
+
+        Some(
+            solution
+                .into_iter()
+                .map(|idx| self.items[idx].clone())
+                .collect(),
+        )
+    }
+}
+
+// An item reduced to its position, weight, and value, so that the generic
+// solvers can be reused on derived instances.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct IndexedEntry {
+    pub(crate) index: usize,
+    pub(crate) weight: usize,
+    pub(crate) value: usize,
+}
+
+impl Weight for IndexedEntry {
+    fn weight(&self) -> usize {
+        self.weight
+    }
+}
+
+impl Value for IndexedEntry {
+    fn value(&self) -> usize {
+        self.value
+    }
+}
+
+// Collects the `(weight, value)` pair of every item.
+pub(crate) fn entries<T>(items: &[T]) -> Vec<(usize, usize)>
+where
+    T: Weight + Value,
+{
+    items
+        .iter()
+        .map(|item| (item.weight(), item.value()))
+        .collect()
+}
+
+// Solves a 0/1 knapsack over `(weight, value)` entries, returning the optimal
+// value and the indices of a selected subset in increasing order.
+pub(crate) fn solve(entries: &[(usize, usize)], capacity: usize) -> (usize, Vec<usize>) {
+    let dp = dp_table(entries, capacity);
+    (
+        dp[entries.len()][capacity],
+        backtrack(&dp, entries, capacity),
+    )
+}
+
+// Recovers the indices of an optimal subset for `capacity` from a table built
+// by `dp_table` for at least that capacity.
+pub(crate) fn backtrack(
+    dp: &[Vec<usize>],
+    entries: &[(usize, usize)],
+    capacity: usize,
+) -> Vec<usize> {
+    let mut current_w = capacity;
+    let mut indices = Vec::new();
+
+    for i in (1..=entries.len()).rev() {
+        let item_weight = entries[i - 1].0;
+        if current_w >= item_weight && dp[i][current_w] != dp[i - 1][current_w] {
+            indices.push(i - 1);
+            current_w -= item_weight;
+        }
+    }
+
+    indices.reverse();
+    indices
+}
+
+// Item count limits of a `LexTable`.
+#[derive(Clone, Copy)]
+enum CountLimits {
+    // At least `min` items; count `k` means at least `k` more items are
+    // needed, so the table only grows with `min`. `AtLeast(0)` is unconstrained.
+    AtLeast(usize),
+    // Between `min` and `max` items; count `k` means exactly `k` more items.
+    Between(usize, usize),
+}
+
+impl CountLimits {
+    // The count left after taking an entry at count `k`, if taking is allowed.
+    fn previous(self, k: usize) -> Option<usize> {
+        match self {
+            CountLimits::AtLeast(_) => Some(k.saturating_sub(1)),
+            CountLimits::Between(..) => k.checked_sub(1),
+        }
+    }
+}
+
+// Suffix DP table over lexicographic keys. Keys of entries are added
+// component-wise and compared lexicographically, so `keys[i][0]` is usually
+// the value and later components are secondary objectives.
+//
+// Cell `(i, w, k)` holds the best key achievable with entries `i..` and
+// capacity `w`, if any subset fits, where `k` is a count whose meaning
+// depends on the `CountLimits`. The table has `counts` cells per `(i, w)`, so
+// it takes O(n * capacity * counts) memory.
+struct LexTable<'a> {
+    entries: &'a [(usize, usize)],
+    keys: &'a [Vec<i64>],
+    capacity: usize,
+    width: usize,
+    count_limits: CountLimits,
+    counts: usize,
+    feasible: Vec<bool>,
+    data: Vec<i64>,
+}
+
+impl<'a> LexTable<'a> {
+    fn new(
+        entries: &'a [(usize, usize)],
+        keys: &'a [Vec<i64>],
+        capacity: usize,
+        count_limits: CountLimits,
+    ) -> Self {
+        let n = entries.len();
+        let width = keys.first().map_or(1, Vec::len);
+        let counts = match count_limits {
+            CountLimits::AtLeast(min) => min + 1,
+            CountLimits::Between(_, max) => max + 1,
+        };
+        let cells = (n + 1) * (capacity + 1) * counts;
+        let mut table = LexTable {
+            entries,
+            keys,
+            capacity,
+            width,
+            count_limits,
+            counts,
+            feasible: vec![false; cells],
+            data: vec![0; cells * width],
+        };
+
+        for w in 0..=capacity {
+            let base = table.index(n, w, 0);
+            table.feasible[base] = true;
+        }
+        for i in (0..n).rev() {
+            let item_weight = entries[i].0;
+            for w in 0..=capacity {
+                for k in 0..counts {
+                    let mut best = table.cell(i + 1, w, k).map(<[i64]>::to_vec);
+                    let previous_k = count_limits.previous(k);
+                    if let (true, Some(previous_k)) = (item_weight <= w, previous_k) {
+                        if let Some(rest) = table.cell(i + 1, w - item_weight, previous_k) {
+                            let take: Vec<i64> =
+                                keys[i].iter().zip(rest).map(|(a, b)| a + b).collect();
+                            if best.as_ref().is_none_or(|best| take > *best) {
+                                best = Some(take);
+                            }
+                        }
+                    }
+                    if let Some(best) = best {
+                        let at = table.index(i, w, k);
+                        table.feasible[at] = true;
+                        table.data[at * width..(at + 1) * width].copy_from_slice(&best);
+                    }
+                }
+            }
+        }
+        table
+    }
+
+    fn index(&self, i: usize, w: usize, k: usize) -> usize {
+        (i * (self.capacity + 1) + w) * self.counts + k
+    }
+
+    fn cell(&self, i: usize, w: usize, k: usize) -> Option<&[i64]> {
+        let at = self.index(i, w, k);
+        self.feasible[at].then(|| &self.data[at * self.width..(at + 1) * self.width])
+    }
+
+    // Returns the optimal key and the item counts achieving it.
+    fn optimum(&self) -> Option<(Vec<i64>, Vec<usize>)> {
+        let (min, max) = match self.count_limits {
+            CountLimits::AtLeast(min) => (min, min),
+            CountLimits::Between(min, max) => (min, max),
+        };
+        let mut optimum: Option<(Vec<i64>, Vec<usize>)> = None;
+        for k in min..=max {
+            let Some(key) = self.cell(0, self.capacity, k) else {
+                continue;
+            };
+            match &mut optimum {
+                Some((best, ks)) if key == best.as_slice() => ks.push(k),
+                Some((best, _)) if key < best.as_slice() => {}
+                _ => optimum = Some((key.to_vec(), vec![k])),
+            }
+        }
+        optimum
+    }
+
+    // Whether taking entry `i` at `(w, k)` leads to exactly `target`.
+    fn take_reaches(&self, i: usize, w: usize, k: usize, target: &[i64]) -> bool {
+        let item_weight = self.entries[i].0;
+        match (item_weight <= w, self.count_limits.previous(k)) {
+            (true, Some(previous_k)) => {
+                self.cell(i + 1, w - item_weight, previous_k)
+                    .is_some_and(|rest| {
+                        self.keys[i]
+                            .iter()
+                            .zip(rest)
+                            .zip(target)
+                            .all(|((a, b), t)| a + b == *t)
+                    })
+            }
+            _ => false,
+        }
+    }
+
+    fn take_state(&self, i: usize, w: usize, k: usize, target: &[i64]) -> (usize, usize, Vec<i64>) {
+        let previous_k = self
+            .count_limits
+            .previous(k)
+            .expect("taking an entry is allowed at this count");
+        let rest = target
+            .iter()
+            .zip(&self.keys[i])
+            .map(|(t, a)| t - a)
+            .collect();
+        (w - self.entries[i].0, previous_k, rest)
+    }
+
+    // Returns the optimal subset with the lexicographically smallest sorted
+    // indices, or `None` if no subset satisfies the count limits.
+    fn lowest_indices(&self) -> Option<Vec<usize>> {
+        let (mut target, counts) = self.optimum()?;
+        let mut k = counts[0];
+        let mut w = self.capacity;
+        let mut indices = Vec::new();
+        for i in 0..self.entries.len() {
+            // Stopping as soon as the empty completion suffices keeps indices minimal.
+            let done = match self.count_limits {
+                CountLimits::AtLeast(_) => k == 0 && target.iter().all(|&component| component == 0),
+                CountLimits::Between(..) => k == 0,
+            };
+            if done {
+                break;
+            }
+            if self.take_reaches(i, w, k, &target) {
+                (w, k, target) = self.take_state(i, w, k, &target);
+                indices.push(i);
+            }
+        }
+        Some(indices)
+    }
+
+    // Lazily enumerates the sorted indices of every optimal subset, keeping
+    // only the partial solutions of the current depth-first path in memory.
+    fn all_optimal(&self) -> impl Iterator<Item = Vec<usize>> + '_ {
+        // Partial solutions: (next entry, capacity, count, remaining target, taken indices).
+        let mut stack: Vec<_> = match self.optimum() {
+            Some((target, counts)) => counts
+                .into_iter()
+                .map(|k| (0, self.capacity, k, target.clone(), Vec::new()))
+                .collect(),
+            None => Vec::new(),
+        };
+        std::iter::from_fn(move || {
+            while let Some((i, w, k, target, taken)) = stack.pop() {
+                if i == self.entries.len() {
+                    return Some(taken);
+                }
+                if self.cell(i + 1, w, k) == Some(target.as_slice()) {
+                    stack.push((i + 1, w, k, target.clone(), taken.clone()));
+                }
+                if self.take_reaches(i, w, k, &target) {
+                    let (w, k, target) = self.take_state(i, w, k, &target);
+                    let mut with_item = taken;
+                    with_item.push(i);
+                    stack.push((i + 1, w, k, target, with_item));
+                }
+            }
+            None
+        })
+    }
+}
+
+// Builds the classic 0/1 knapsack table where `dp[i][w]` is the best value
+// achievable with the first `i` entries and capacity `w`.
+pub(crate) fn dp_table(entries: &[(usize, usize)], capacity: usize) -> Vec<Vec<usize>> {
+    let mut dp = Vec::with_capacity(entries.len() + 1);
+    dp.push(vec![0; capacity + 1]);
+
+    for &entry in entries {
+        let row = dp_row(&dp[dp.len() - 1], entry);
+        dp.push(row);
+    }
+
+    dp
+}
+
+// Computes the DP row obtained by adding one `(weight, value)` entry to the
+// instance described by `previous`.
+pub(crate) fn dp_row(previous: &[usize], (item_weight, item_value): (usize, usize)) -> Vec<usize> {
+    (0..previous.len())
+        .map(|w| {
+            let value_without_item = previous[w];
+            if item_weight <= w {
+                let value_with_item = previous[w - item_weight] + item_value;
+                value_without_item.max(value_with_item)
+            } else {
+                value_without_item
+            }
+        })
+        .collect()
+}
+
+// Unbounded knapsack DP over exact fills: `best[c]` is the best key of a
+// multiset of entries with total weight exactly `c`, where `extend` adds one
+// copy of an entry to a key and `better` compares keys. `last[c]` is the
+// entry added last to reach `best[c]`. Entries of zero weight are ignored.
+pub(crate) fn unbounded_table<K: Copy>(
+    weights: &[usize],
+    capacity: usize,
+    empty: K,
+    extend: impl Fn(K, usize) -> K,
+    better: impl Fn(&K, &K) -> bool,
+) -> (Vec<Option<K>>, Vec<Option<usize>>) {
+    let mut best = vec![None; capacity + 1];
+    let mut last = vec![None; capacity + 1];
+    best[0] = Some(empty);
+    for c in 1..=capacity {
+        for (i, &weight) in weights.iter().enumerate() {
+            if weight == 0 || weight > c {
+                continue;
+            }
+            if let Some(previous) = best[c - weight] {
+                let candidate = extend(previous, i);
+                if best[c].is_none_or(|current| better(&candidate, &current)) {
+                    best[c] = Some(candidate);
+                    last[c] = Some(i);
+                }
+            }
+        }
+    }
+    (best, last)
+}
+
+// Recovers the number of copies of every entry in the multiset reaching
+// `capacity` in a table built by `unbounded_table`.
+pub(crate) fn unbounded_counts(
+    weights: &[usize],
+    last: &[Option<usize>],
+    capacity: usize,
+) -> Vec<usize> {
+    let mut counts = vec![0; weights.len()];
+    let mut c = capacity;
+    while let Some(i) = last[c] {
+        counts[i] += 1;
+        c -= weights[i];
+    }
+    counts
+}
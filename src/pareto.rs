@@ -0,0 +1,265 @@
+use crate::traits::{Values, Weight};
+
+/// The direction in which an objective of a multi-objective solver is optimized.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Sense {
+    /// Larger totals are better.
+    Maximize,
+    /// Smaller totals are better.
+    Minimize,
+}
+
+/// A nondominated solution of a multi-objective knapsack instance.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParetoSolution<T> {
+    /// The selected items, in input order.
+    pub items: Vec<T>,
+    /// The total of each objective over the selected items.
+    pub objectives: Vec<usize>,
+    /// The total weight of the selected items.
+    pub weight: usize,
+}
+
+/// An iterator over the Pareto front of a multi-objective 0/1 knapsack instance.
+///
+/// Every item has a weight and several values (see `Values`). A feasible subset
+/// is nondominated if no other feasible subset is at least as good on every
+/// objective and strictly better on one. The iterator yields one solution per
+/// nondominated objective vector, sorted from best to worst on the first
+/// objective.
+///
+/// The front is computed with the Nemhauser–Ullmann algorithm: items are added
+/// one by one, merging the current list of partial solutions with its
+/// extension by the new item and pruning every partial solution dominated in
+/// both weight and objectives.
+///
+/// # Examples
+///
+/// ```
+/// use knap::pareto::{ParetoFrontIterator, Sense};
+/// use knap::traits::{Values, Weight};
+///
+/// #[derive(Clone, Debug, PartialEq)]
+/// struct Project {
+///     id: char,
+///     cost: usize,
+///     profit: usize,
+///     risk: usize,
+/// }
+///
+/// impl Weight for Project {
+///     fn weight(&self) -> usize {
+///         self.cost
+///     }
+/// }
+///
+/// impl Values for Project {
+///     fn values(&self) -> Vec<usize> {
+///         vec![self.profit, self.risk]
+///     }
+/// }
+///
+/// let projects = vec![
+///     Project { id: 'A', cost: 2, profit: 10, risk: 8 },
+///     Project { id: 'B', cost: 2, profit: 6, risk: 1 },
+///     Project { id: 'C', cost: 3, profit: 5, risk: 4 },
+/// ];
+///
+/// let front: Vec<(Vec<usize>, Vec<char>)> = ParetoFrontIterator::new(projects, 4)
+///     .with_senses([Sense::Maximize, Sense::Minimize])
+///     .map(|solution| (solution.objectives, solution.items.iter().map(|p| p.id).collect()))
+///     .collect();
+///
+/// assert_eq!(
+///     front,
+///     vec![
+///         (vec![16, 9], vec!['A', 'B']),
+///         (vec![10, 8], vec!['A']),
+///         (vec![6, 1], vec!['B']),
+///         (vec![0, 0], vec![]),
+///     ]
+/// );
+/// ```
+#[derive(Debug)]
+pub struct ParetoFrontIterator<T>
+where
+    T: Weight + Values + Clone,
+{
+    items: Vec<T>,
+    capacity: usize,
+    senses: Vec<Sense>,
+    front: Vec<ParetoSolution<T>>,
+    current_index: usize,
+    // Ensures the front is computed only once.
+    computed: bool,
+}
+
+// A partial solution; `item` and `parent` link back to the labels it extends.
+#[derive(Debug)]
+struct Label {
+    weight: usize,
+    // Objective totals, negated for minimized objectives so larger is better.
+    score: Vec<i64>,
+    item: Option<usize>,
+    parent: Option<usize>,
+}
+
+impl<T> ParetoFrontIterator<T>
+where
+    T: Weight + Values + Clone,
+{
+    /// Creates a new `ParetoFrontIterator` maximizing every objective.
+    ///
+    /// # Arguments
+    ///
+    /// * `input_items`: An iterator over items that implement `Weight`, `Values`, and `Clone`.
+    /// * `capacity`: The maximum capacity of the knapsack.
+    pub fn new(input_items: impl IntoIterator<Item = T>, capacity: usize) -> Self {
+        let items: Vec<T> = input_items.into_iter().collect();
+        ParetoFrontIterator {
+            items,
+            capacity,
+            senses: Vec::new(),
+            front: Vec::new(),
+            current_index: 0,
+            computed: false,
+        }
+    }
+
+    /// Sets the direction of each objective, in the order returned by `Values::values`.
+    ///
+    /// Objectives without an explicit sense are maximized.
+    pub fn with_senses(mut self, senses: impl IntoIterator<Item = Sense>) -> Self {
+        self.senses = senses.into_iter().collect();
+        self
+    }
+
+    fn sense(&self, objective: usize) -> Sense {
+        self.senses
+            .get(objective)
+            .copied()
+            .unwrap_or(Sense::Maximize)
+    }
+
+    fn compute_front(&mut self) {
+        let objective_count = self.items.first().map_or(0, |item| item.values().len());
+        let mut labels = vec![Label {
+            weight: 0,
+            score: vec![0; objective_count],
+            item: None,
+            parent: None,
+        }];
+        let mut current: Vec<usize> = vec![0];
+
+        for (idx, item) in self.items.iter().enumerate() {
+            let item_weight = item.weight();
+            let item_score: Vec<i64> = item
+                .values()
+                .into_iter()
+                .enumerate()
+                .map(|(objective, value)| match self.sense(objective) {
+                    Sense::Maximize => value as i64,
+                    Sense::Minimize => -(value as i64),
+                })
+                .collect();
+
+            let mut candidates = current.clone();
+            for &parent in &current {
+                let weight = labels[parent].weight + item_weight;
+                if weight > self.capacity {
+                    continue;
+                }
+                let score = labels[parent]
+                    .score
+                    .iter()
+                    .zip(&item_score)
+                    .map(|(a, b)| a + b)
+                    .collect();
+                labels.push(Label {
+                    weight,
+                    score,
+                    item: Some(idx),
+                    parent: Some(parent),
+                });
+                candidates.push(labels.len() - 1);
+            }
+
+            current = nondominated(&labels, &candidates, true);
+        }
+
+        let mut front = nondominated(&labels, &current, false);
+        front.sort_by(|&a, &b| labels[b].score.cmp(&labels[a].score));
+
+        self.front = front
+            .into_iter()
+            .map(|label| {
+                let mut indices = Vec::new();
+                let mut cursor = Some(label);
+                while let Some(id) = cursor {
+                    indices.extend(labels[id].item);
+                    cursor = labels[id].parent;
+                }
+                indices.reverse();
+                ParetoSolution {
+                    items: indices.iter().map(|&idx| self.items[idx].clone()).collect(),
+                    objectives: labels[label]
+                        .score
+                        .iter()
+                        .map(|s| s.unsigned_abs() as usize)
+                        .collect(),
+                    weight: labels[label].weight,
+                }
+            })
+            .collect();
+    }
+}
+
+// Keeps the labels not dominated by any other candidate. When `with_weight`
+// is set, a lighter weight also counts as an advantage. Among labels with
+// identical criteria, the first one is kept.
+fn nondominated(labels: &[Label], candidates: &[usize], with_weight: bool) -> Vec<usize> {
+    let dominates = |a: &Label, b: &Label| {
+        (!with_weight || a.weight <= b.weight) && a.score.iter().zip(&b.score).all(|(x, y)| x >= y)
+    };
+    let identical =
+        |a: &Label, b: &Label| (!with_weight || a.weight == b.weight) && a.score == b.score;
+
+    candidates
+        .iter()
+        .enumerate()
+        .filter(|&(position, &id)| {
+            candidates
+                .iter()
+                .enumerate()
+                .all(|(other_position, &other)| {
+                    if other_position == position || !dominates(&labels[other], &labels[id]) {
+                        return true;
+                    }
+                    identical(&labels[other], &labels[id]) && other_position > position
+                })
+        })
+        .map(|(_, &id)| id)
+        .collect()
+}
+
+impl<T> Iterator for ParetoFrontIterator<T>
+where
+    T: Weight + Values + Clone,
+{
+    type Item = ParetoSolution<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.computed {
+            self.compute_front();
+            self.computed = true;
+        }
+
+        if self.current_index < self.front.len() {
+            let solution = self.front[self.current_index].clone();
+            self.current_index += 1;
+            Some(solution)
+        } else {
+            None
+        }
+    }
+}
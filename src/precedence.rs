@@ -0,0 +1,377 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::traits::{Value, Weight};
+
+/// An error in the precedence constraints given to `PrecedenceKnapsackIterator`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PrecedenceError {
+    /// A precedence refers to an item index that is out of bounds.
+    IndexOutOfBounds(usize),
+    /// The precedences contain a cycle, listed as input indices where each
+    /// item requires the next one and the last requires the first.
+    Cycle(Vec<usize>),
+}
+
+impl fmt::Display for PrecedenceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PrecedenceError::IndexOutOfBounds(index) => {
+                write!(
+                    f,
+                    "precedence refers to item {index}, which is out of bounds"
+                )
+            }
+            PrecedenceError::Cycle(cycle) => {
+                write!(f, "precedences contain a cycle through items {cycle:?}")
+            }
+        }
+    }
+}
+
+impl Error for PrecedenceError {}
+
+/// An iterator that yields items of an optimal precedence-constrained knapsack solution.
+///
+/// Precedences are pairs `(a, b)` of input indices meaning that item `b` can
+/// only be selected if item `a` is selected too, forming a directed acyclic
+/// graph. When every item has at most one predecessor (a forest), the instance
+/// is solved by the tree knapsack dynamic program over a preorder traversal in
+/// O(n * capacity). General graphs are solved by depth-first branch and bound
+/// in topological order, bounded by the fractional knapsack over the items
+/// that can still be selected.
+///
+/// # Examples
+///
+/// ```
+/// use knap::precedence::PrecedenceKnapsackIterator;
+/// use knap::traits::{Value, Weight};
+///
+/// #[derive(Clone, Debug, PartialEq)]
+/// struct Feature {
+///     id: char,
+///     effort: usize,
+///     value: usize,
+/// }
+///
+/// impl Weight for Feature {
+///     fn weight(&self) -> usize {
+///         self.effort
+///     }
+/// }
+///
+/// impl Value for Feature {
+///     fn value(&self) -> usize {
+///         self.value
+///     }
+/// }
+///
+/// let features = vec![
+///     Feature { id: 'A', effort: 3, value: 1 },
+///     Feature { id: 'B', effort: 2, value: 10 },
+///     Feature { id: 'C', effort: 4, value: 6 },
+/// ];
+///
+/// // B requires A.
+/// let ids: Vec<char> = PrecedenceKnapsackIterator::new(features, 6, [(0, 1)])
+///     .unwrap()
+///     .map(|feature| feature.id)
+///     .collect();
+/// assert_eq!(ids, vec!['A', 'B']);
+/// ```
+#[derive(Debug)]
+pub struct PrecedenceKnapsackIterator<T>
+where
+    T: Weight + Value + Clone,
+{
+    items: Vec<T>,
+    capacity: usize,
+    predecessors: Vec<Vec<usize>>,
+    successors: Vec<Vec<usize>>,
+    // A topological order of the items.
+    order: Vec<usize>,
+    solution_items: Vec<T>,
+    current_index: usize,
+    // Ensures the solver is run only once.
+    computed: bool,
+}
+
+impl<T> PrecedenceKnapsackIterator<T>
+where
+    T: Weight + Value + Clone,
+{
+    /// Creates a new `PrecedenceKnapsackIterator`.
+    ///
+    /// # Arguments
+    ///
+    /// * `input_items`: An iterator over items that implement `Weight`, `Value`, and `Clone`.
+    /// * `capacity`: The maximum capacity of the knapsack.
+    /// * `precedences`: Pairs `(a, b)` of input indices meaning `b` requires `a`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a precedence refers to an index out of bounds or if
+    /// the precedences contain a cycle.
+    pub fn new(
+        input_items: impl IntoIterator<Item = T>,
+        capacity: usize,
+        precedences: impl IntoIterator<Item = (usize, usize)>,
+    ) -> Result<Self, PrecedenceError> {
+        let items: Vec<T> = input_items.into_iter().collect();
+        let n = items.len();
+        let mut predecessors = vec![Vec::new(); n];
+        let mut successors = vec![Vec::new(); n];
+        for (a, b) in precedences {
+            if let Some(&index) = [a, b].iter().find(|&&index| index >= n) {
+                return Err(PrecedenceError::IndexOutOfBounds(index));
+            }
+            predecessors[b].push(a);
+            successors[a].push(b);
+        }
+        for list in predecessors.iter_mut().chain(successors.iter_mut()) {
+            list.sort_unstable();
+            list.dedup();
+        }
+        let order = topological_order(&predecessors, &successors)?;
+
+        Ok(PrecedenceKnapsackIterator {
+            items,
+            capacity,
+            predecessors,
+            successors,
+            order,
+            solution_items: Vec::new(),
+            current_index: 0,
+            computed: false,
+        })
+    }
+
+    fn compute_solution(&mut self) {
+        let weights: Vec<usize> = self.items.iter().map(Weight::weight).collect();
+        let values: Vec<usize> = self.items.iter().map(Value::value).collect();
+
+        let mut indices = if self.predecessors.iter().all(|preds| preds.len() <= 1) {
+            self.solve_forest(&weights, &values)
+        } else {
+            self.solve_dag(&weights, &values)
+        };
+        indices.sort_unstable();
+        self.solution_items = indices
+            .into_iter()
+            .map(|idx| self.items[idx].clone())
+            .collect();
+    }
+
+    // Tree knapsack over a preorder traversal: at each position, either take
+    // the item and continue with its children, or skip its whole subtree.
+    fn solve_forest(&self, weights: &[usize], values: &[usize]) -> Vec<usize> {
+        let n = self.items.len();
+        let mut preorder = Vec::with_capacity(n);
+        let mut stack: Vec<usize> = (0..n)
+            .rev()
+            .filter(|&idx| self.predecessors[idx].is_empty())
+            .collect();
+        while let Some(idx) = stack.pop() {
+            preorder.push(idx);
+            stack.extend(self.successors[idx].iter().rev());
+        }
+
+        // `subtree_end[p]` is the preorder position right after the subtree at `p`.
+        let mut subtree_end = vec![0; n];
+        let mut position_of = vec![0; n];
+        for (position, &idx) in preorder.iter().enumerate() {
+            position_of[idx] = position;
+        }
+        for position in (0..n).rev() {
+            let idx = preorder[position];
+            subtree_end[position] = self.successors[idx]
+                .iter()
+                .map(|&child| subtree_end[position_of[child]])
+                .max()
+                .unwrap_or(position + 1);
+        }
+
+        let columns = self.capacity + 1;
+        let mut dp = vec![vec![0; columns]; n + 1];
+        for position in (0..n).rev() {
+            let idx = preorder[position];
+            for w in 0..columns {
+                let skip = dp[subtree_end[position]][w];
+                dp[position][w] = if weights[idx] <= w {
+                    skip.max(values[idx] + dp[position + 1][w - weights[idx]])
+                } else {
+                    skip
+                };
+            }
+        }
+
+        let mut indices = Vec::new();
+        let mut position = 0;
+        let mut w = self.capacity;
+        while position < n {
+            let idx = preorder[position];
+            if dp[position][w] == dp[subtree_end[position]][w] {
+                position = subtree_end[position];
+            } else {
+                indices.push(idx);
+                w -= weights[idx];
+                position += 1;
+            }
+        }
+        indices
+    }
+
+    fn solve_dag(&self, weights: &[usize], values: &[usize]) -> Vec<usize> {
+        let ratio = |idx: usize| match (weights[idx], values[idx]) {
+            (0, 0) => -1.0,
+            (0, _) => f64::MAX,
+            (weight, value) => value as f64 / weight as f64,
+        };
+        let mut by_ratio: Vec<usize> = (0..self.items.len()).collect();
+        by_ratio.sort_by(|&a, &b| ratio(b).total_cmp(&ratio(a)).then(a.cmp(&b)));
+
+        let mut search = DagSearch {
+            order: &self.order,
+            by_ratio,
+            successors: &self.successors,
+            weights,
+            values,
+            excluded_predecessors: vec![0; self.items.len()],
+            decided: vec![false; self.items.len()],
+            chosen: Vec::new(),
+            best: Vec::new(),
+            best_value: 0,
+        };
+        search.branch(0, self.capacity, 0);
+        search.best
+    }
+}
+
+impl<T> Iterator for PrecedenceKnapsackIterator<T>
+where
+    T: Weight + Value + Clone,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.computed {
+            self.compute_solution();
+            self.computed = true;
+        }
+
+        if self.current_index < self.solution_items.len() {
+            let item = self.solution_items[self.current_index].clone();
+            self.current_index += 1;
+            Some(item)
+        } else {
+            None
+        }
+    }
+}
+
+// State of the depth-first branch and bound over a topological order.
+struct DagSearch<'a> {
+    order: &'a [usize],
+    by_ratio: Vec<usize>,
+    successors: &'a [Vec<usize>],
+    weights: &'a [usize],
+    values: &'a [usize],
+    // Number of unselected predecessors of each item.
+    excluded_predecessors: Vec<usize>,
+    decided: Vec<bool>,
+    chosen: Vec<usize>,
+    best: Vec<usize>,
+    best_value: usize,
+}
+
+impl DagSearch<'_> {
+    fn branch(&mut self, position: usize, remaining: usize, value: usize) {
+        if value > self.best_value {
+            self.best_value = value;
+            self.best = self.chosen.clone();
+        }
+        if position == self.order.len() || value + self.bound(remaining) <= self.best_value {
+            return;
+        }
+
+        let idx = self.order[position];
+        self.decided[idx] = true;
+        if self.excluded_predecessors[idx] == 0 && self.weights[idx] <= remaining {
+            self.chosen.push(idx);
+            self.branch(
+                position + 1,
+                remaining - self.weights[idx],
+                value + self.values[idx],
+            );
+            self.chosen.pop();
+        }
+        for &successor in &self.successors[idx] {
+            self.excluded_predecessors[successor] += 1;
+        }
+        self.branch(position + 1, remaining, value);
+        for &successor in &self.successors[idx] {
+            self.excluded_predecessors[successor] -= 1;
+        }
+        self.decided[idx] = false;
+    }
+
+    // Fractional knapsack over the undecided items that may still be selected.
+    fn bound(&self, remaining: usize) -> usize {
+        let mut bound = 0.0;
+        let mut room = remaining;
+        for &idx in &self.by_ratio {
+            if self.decided[idx] || self.excluded_predecessors[idx] > 0 {
+                continue;
+            }
+            if self.weights[idx] <= room {
+                room -= self.weights[idx];
+                bound += self.values[idx] as f64;
+            } else {
+                bound += self.values[idx] as f64 * room as f64 / self.weights[idx] as f64;
+                break;
+            }
+        }
+        bound.floor() as usize
+    }
+}
+
+// Kahn's algorithm, returning a cycle if the graph is not acyclic.
+fn topological_order(
+    predecessors: &[Vec<usize>],
+    successors: &[Vec<usize>],
+) -> Result<Vec<usize>, PrecedenceError> {
+    let n = predecessors.len();
+    let mut pending: Vec<usize> = predecessors.iter().map(Vec::len).collect();
+    let mut ready: Vec<usize> = (0..n).rev().filter(|&idx| pending[idx] == 0).collect();
+    let mut order = Vec::with_capacity(n);
+    while let Some(idx) = ready.pop() {
+        order.push(idx);
+        for &successor in successors[idx].iter().rev() {
+            pending[successor] -= 1;
+            if pending[successor] == 0 {
+                ready.push(successor);
+            }
+        }
+    }
+    if order.len() == n {
+        return Ok(order);
+    }
+
+    // Every remaining item has a remaining predecessor, so walking backwards
+    // through them must eventually revisit an item.
+    let mut seen_at = vec![None; n];
+    let mut path = Vec::new();
+    let mut current = (0..n).find(|&idx| pending[idx] > 0).unwrap_or(0);
+    while seen_at[current].is_none() {
+        seen_at[current] = Some(path.len());
+        path.push(current);
+        current = predecessors[current]
+            .iter()
+            .copied()
+            .find(|&pred| pending[pred] > 0)
+            .unwrap_or(current);
+    }
+    Err(PrecedenceError::Cycle(
+        path.split_off(seen_at[current].unwrap_or(0)),
+    ))
+}
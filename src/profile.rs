@@ -0,0 +1,142 @@
+use crate::optimal::{backtrack, dp_table, entries};
+use crate::traits::{Value, Weight};
+
+/// The optimal knapsack value as a function of capacity.
+///
+/// A single dynamic programming pass up to `max_capacity` yields the optimal
+/// value for every smaller capacity as well. `CapacityProfile` keeps the whole
+/// table, so the optimal value and an optimal subset can be queried for any
+/// capacity in `0..=max_capacity` without solving the instance again.
+///
+/// For any positive capacity, `solution` returns the same subset as
+/// `KnapsackIterator`.
+///
+/// # Examples
+///
+/// ```
+/// use knap::profile::CapacityProfile;
+/// use knap::traits::{Value, Weight};
+///
+/// #[derive(Clone, Debug, PartialEq)]
+/// struct Item {
+///     id: char,
+///     weight: usize,
+///     value: usize,
+/// }
+///
+/// impl Weight for Item {
+///     fn weight(&self) -> usize {
+///         self.weight
+///     }
+/// }
+///
+/// impl Value for Item {
+///     fn value(&self) -> usize {
+///         self.value
+///     }
+/// }
+///
+/// let items = vec![
+///     Item { id: 'A', weight: 10, value: 60 },
+///     Item { id: 'B', weight: 20, value: 100 },
+///     Item { id: 'C', weight: 30, value: 120 },
+/// ];
+///
+/// let profile = CapacityProfile::new(items, 50);
+///
+/// assert_eq!(profile.value(25), Some(100));
+/// assert_eq!(
+///     profile.steps(),
+///     vec![(0, 0), (10, 60), (20, 100), (30, 160), (40, 180), (50, 220)]
+/// );
+///
+/// let ids: Vec<char> = profile.solution(35).unwrap().iter().map(|item| item.id).collect();
+/// assert_eq!(ids, vec!['A', 'B']);
+/// ```
+#[derive(Debug)]
+pub struct CapacityProfile<T>
+where
+    T: Weight + Value + Clone,
+{
+    items: Vec<T>,
+    entries: Vec<(usize, usize)>,
+    max_capacity: usize,
+    dp: Vec<Vec<usize>>,
+}
+
+impl<T> CapacityProfile<T>
+where
+    T: Weight + Value + Clone,
+{
+    /// Creates a new `CapacityProfile` covering capacities `0..=max_capacity`.
+    ///
+    /// # Arguments
+    ///
+    /// * `input_items`: An iterator over items that implement `Weight`, `Value`, and `Clone`.
+    /// * `max_capacity`: The largest capacity that can be queried.
+    pub fn new(input_items: impl IntoIterator<Item = T>, max_capacity: usize) -> Self {
+        let items: Vec<T> = input_items.into_iter().collect();
+        let entries = entries(&items);
+        let dp = dp_table(&entries, max_capacity);
+
+        CapacityProfile {
+            items,
+            entries,
+            max_capacity,
+            dp,
+        }
+    }
+
+    /// Returns the largest capacity covered by this profile.
+    pub fn max_capacity(&self) -> usize {
+        self.max_capacity
+    }
+
+    /// Returns the optimal value for `capacity`, or `None` if it exceeds `max_capacity`.
+    pub fn value(&self, capacity: usize) -> Option<usize> {
+        self.dp[self.items.len()].get(capacity).copied()
+    }
+
+    /// Returns the optimal value for every capacity in `0..=max_capacity`.
+    pub fn values(&self) -> &[usize] {
+        &self.dp[self.items.len()]
+    }
+
+    /// Returns the breakpoints of the step function as `(capacity, value)` pairs.
+    ///
+    /// The first pair is always for capacity zero, and each following pair is
+    /// the smallest capacity at which the optimal value increases.
+    pub fn steps(&self) -> Vec<(usize, usize)> {
+        let mut steps: Vec<(usize, usize)> = Vec::new();
+        for (capacity, &value) in self.values().iter().enumerate() {
+            if steps.last().is_none_or(|&(_, last)| value > last) {
+                steps.push((capacity, value));
+            }
+        }
+        steps
+    }
+
+    /// Returns an optimal subset for `capacity`, or `None` if it exceeds `max_capacity`.
+    ///
+    /// The items are in input order.
+    pub fn solution(&self, capacity: usize) -> Option<Vec<T>> {
+        if capacity > self.max_capacity {
+            return None;
+        }
+        let indices = backtrack(&self.dp, &self.entries, capacity);
+        Some(
+            indices
+                .into_iter()
+                .map(|idx| self.items[idx].clone())
+                .collect(),
+        )
+    }
+
+    /// Answers a batch of queries, returning `solution` for every capacity.
+    pub fn solutions(&self, capacities: impl IntoIterator<Item = usize>) -> Vec<Option<Vec<T>>> {
+        capacities
+            .into_iter()
+            .map(|capacity| self.solution(capacity))
+            .collect()
+    }
+}
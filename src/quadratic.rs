@@ -0,0 +1,450 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::traits::{Value, Weight};
+
+/// An error in the pair profits given to `QuadraticKnapsackIterator`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum QuadraticError {
+    /// A pair refers to an item index that is out of bounds.
+    IndexOutOfBounds(usize),
+}
+
+impl fmt::Display for QuadraticError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            QuadraticError::IndexOutOfBounds(index) => {
+                write!(f, "pair refers to item {index}, which is out of bounds")
+            }
+        }
+    }
+}
+
+impl Error for QuadraticError {}
+
+/// The algorithm used by `QuadraticKnapsackIterator`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuadraticMethod {
+    /// A constructive heuristic: starting from all items, the item with the
+    /// smallest profit contribution per unit of weight is dropped until the
+    /// selection fits, then items with the largest gain per unit of weight
+    /// are added while they fit.
+    Greedy,
+    /// The greedy solution improved by best-improvement local search over
+    /// single additions and one-for-one swaps, until no move increases the
+    /// total profit.
+    LocalSearch,
+    /// Exact depth-first branch and bound, starting from the local search
+    /// solution. The running time is exponential in the worst case, so it is
+    /// meant for small instances.
+    Exact,
+}
+
+/// An iterator that yields items of a quadratic knapsack solution.
+///
+/// Besides its own value, every pair of selected items may earn an extra
+/// profit. The pairwise profits are given as a sparse list of
+/// `(first, second, profit)` triples over input indices, and the total profit
+/// of a selection is the sum of its item values plus the profits of all pairs
+/// it contains. Items are yielded in input order.
+///
+/// # Examples
+///
+/// ```
+/// use knap::quadratic::{QuadraticKnapsackIterator, QuadraticMethod};
+/// use knap::traits::{Value, Weight};
+///
+/// #[derive(Clone, Debug, PartialEq)]
+/// struct Item {
+///     id: char,
+///     weight: usize,
+///     value: usize,
+/// }
+///
+/// impl Weight for Item {
+///     fn weight(&self) -> usize {
+///         self.weight
+///     }
+/// }
+///
+/// impl Value for Item {
+///     fn value(&self) -> usize {
+///         self.value
+///     }
+/// }
+///
+/// let items = vec![
+///     Item { id: 'A', weight: 4, value: 10 },
+///     Item { id: 'B', weight: 3, value: 4 },
+///     Item { id: 'C', weight: 3, value: 4 },
+/// ];
+///
+/// // B and C are worth more together than A alone.
+/// let ids: Vec<char> = QuadraticKnapsackIterator::new(items, 6, [(1, 2, 5)], QuadraticMethod::Exact)
+///     .unwrap()
+///     .map(|item| item.id)
+///     .collect();
+/// assert_eq!(ids, vec!['B', 'C']);
+/// ```
+#[derive(Debug)]
+pub struct QuadraticKnapsackIterator<T>
+where
+    T: Weight + Value + Clone,
+{
+    items: Vec<T>,
+    capacity: usize,
+    // Pairwise profits of every item, sorted by partner index.
+    pair_profits: Vec<Vec<(usize, usize)>>,
+    method: QuadraticMethod,
+    solution_items: Vec<T>,
+    current_index: usize,
+    // Ensures the solution is computed only once.
+    computed: bool,
+}
+
+impl<T> QuadraticKnapsackIterator<T>
+where
+    T: Weight + Value + Clone,
+{
+    /// Creates a new `QuadraticKnapsackIterator`.
+    ///
+    /// # Arguments
+    ///
+    /// * `input_items`: An iterator over items that implement `Weight`, `Value`, and `Clone`.
+    /// * `capacity`: The maximum capacity of the knapsack.
+    /// * `pair_profits`: Triples `(first, second, profit)` of input indices and the
+    ///   profit earned when both are selected. Repeated pairs are summed, and a
+    ///   pair of an index with itself adds to the item's value.
+    /// * `method`: The algorithm used to compute the solution.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a pair refers to an index out of bounds.
+    pub fn new(
+        input_items: impl IntoIterator<Item = T>,
+        capacity: usize,
+        pair_profits: impl IntoIterator<Item = (usize, usize, usize)>,
+        method: QuadraticMethod,
+    ) -> Result<Self, QuadraticError> {
+        let items: Vec<T> = input_items.into_iter().collect();
+        let n = items.len();
+        let mut adjacency: Vec<Vec<(usize, usize)>> = vec![Vec::new(); n];
+        for (a, b, profit) in pair_profits {
+            if let Some(&index) = [a, b].iter().find(|&&index| index >= n) {
+                return Err(QuadraticError::IndexOutOfBounds(index));
+            }
+            adjacency[a].push((b, profit));
+            if a != b {
+                adjacency[b].push((a, profit));
+            }
+        }
+        for partners in &mut adjacency {
+            partners.sort_unstable();
+            partners.dedup_by(|duplicate, kept| {
+                let same = duplicate.0 == kept.0;
+                if same {
+                    kept.1 += duplicate.1;
+                }
+                same
+            });
+        }
+        Ok(QuadraticKnapsackIterator {
+            items,
+            capacity,
+            pair_profits: adjacency,
+            method,
+            solution_items: Vec::new(),
+            current_index: 0,
+            computed: false,
+        })
+    }
+
+    fn compute_solution(&mut self) {
+        let n = self.items.len();
+        let weights: Vec<usize> = self.items.iter().map(Weight::weight).collect();
+        // Profits of an item with itself are part of its value.
+        let mut values: Vec<usize> = self.items.iter().map(Value::value).collect();
+        let mut pairs = self.pair_profits.clone();
+        for (idx, partners) in pairs.iter_mut().enumerate() {
+            if let Ok(position) = partners.binary_search_by_key(&idx, |&(partner, _)| partner) {
+                values[idx] += partners.remove(position).1;
+            }
+        }
+
+        let mut selection = Selection::new(&weights, &values, &pairs);
+        selection.greedy(self.capacity);
+        if self.method != QuadraticMethod::Greedy {
+            selection.local_search(self.capacity);
+        }
+        let mut best: Vec<usize> = (0..n).filter(|&idx| selection.selected[idx]).collect();
+
+        if self.method == QuadraticMethod::Exact {
+            let potential = |idx: usize| -> usize {
+                values[idx] + pairs[idx].iter().map(|&(_, profit)| profit).sum::<usize>()
+            };
+            let mut order: Vec<usize> = (0..n).collect();
+            order.sort_by(|&a, &b| {
+                density(potential(b), weights[b])
+                    .total_cmp(&density(potential(a), weights[a]))
+                    .then(a.cmp(&b))
+            });
+            let mut rank = vec![0; n];
+            for (position, &idx) in order.iter().enumerate() {
+                rank[idx] = position;
+            }
+
+            let mut search = Search {
+                order: &order,
+                rank: &rank,
+                weights: &weights,
+                pairs: &pairs,
+                gains: values.clone(),
+                chosen: Vec::new(),
+                best_value: selection.profit,
+                best,
+            };
+            search.branch(0, self.capacity, 0);
+            best = search.best;
+            best.sort_unstable();
+        }
+
+        self.solution_items = best
+            .into_iter()
+            .map(|idx| self.items[idx].clone())
+            .collect();
+    }
+}
+
+impl<T> Iterator for QuadraticKnapsackIterator<T>
+where
+    T: Weight + Value + Clone,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.computed {
+            self.compute_solution();
+            self.computed = true;
+        }
+
+        if self.current_index < self.solution_items.len() {
+            let item = self.solution_items[self.current_index].clone();
+            self.current_index += 1;
+            Some(item)
+        } else {
+            None
+        }
+    }
+}
+
+// Profit per unit of weight, with weightless items first.
+fn density(profit: usize, weight: usize) -> f64 {
+    match (weight, profit) {
+        (0, 0) => -1.0,
+        (0, _) => f64::MAX,
+        (weight, profit) => profit as f64 / weight as f64,
+    }
+}
+
+// Returns the profit of the pair `(a, b)`.
+fn pair_profit(pairs: &[Vec<(usize, usize)>], a: usize, b: usize) -> usize {
+    pairs[a]
+        .binary_search_by_key(&b, |&(partner, _)| partner)
+        .map_or(0, |position| pairs[a][position].1)
+}
+
+// A selection of items maintained for the heuristics.
+struct Selection<'a> {
+    weights: &'a [usize],
+    pairs: &'a [Vec<(usize, usize)>],
+    selected: Vec<bool>,
+    // Value of every item plus its pair profits with the selected items.
+    contributions: Vec<usize>,
+    used: usize,
+    profit: usize,
+}
+
+impl<'a> Selection<'a> {
+    fn new(weights: &'a [usize], values: &[usize], pairs: &'a [Vec<(usize, usize)>]) -> Self {
+        Selection {
+            weights,
+            pairs,
+            selected: vec![false; weights.len()],
+            contributions: values.to_vec(),
+            used: 0,
+            profit: 0,
+        }
+    }
+
+    fn add(&mut self, idx: usize) {
+        self.selected[idx] = true;
+        self.used += self.weights[idx];
+        self.profit += self.contributions[idx];
+        for &(partner, profit) in &self.pairs[idx] {
+            self.contributions[partner] += profit;
+        }
+    }
+
+    fn remove(&mut self, idx: usize) {
+        self.selected[idx] = false;
+        self.used -= self.weights[idx];
+        self.profit -= self.contributions[idx];
+        for &(partner, profit) in &self.pairs[idx] {
+            self.contributions[partner] -= profit;
+        }
+    }
+
+    fn greedy(&mut self, capacity: usize) {
+        let n = self.weights.len();
+        for idx in 0..n {
+            self.add(idx);
+        }
+        while self.used > capacity {
+            let worst = (0..n)
+                .filter(|&idx| self.selected[idx] && self.weights[idx] > 0)
+                .min_by(|&a, &b| {
+                    density(self.contributions[a], self.weights[a])
+                        .total_cmp(&density(self.contributions[b], self.weights[b]))
+                        .then(b.cmp(&a))
+                });
+            match worst {
+                Some(idx) => self.remove(idx),
+                None => break,
+            }
+        }
+        loop {
+            let best = (0..n)
+                .filter(|&idx| {
+                    !self.selected[idx]
+                        && self.contributions[idx] > 0
+                        && self.used + self.weights[idx] <= capacity
+                })
+                .max_by(|&a, &b| {
+                    density(self.contributions[a], self.weights[a])
+                        .total_cmp(&density(self.contributions[b], self.weights[b]))
+                        .then(b.cmp(&a))
+                });
+            match best {
+                Some(idx) => self.add(idx),
+                None => break,
+            }
+        }
+    }
+
+    fn local_search(&mut self, capacity: usize) {
+        let n = self.weights.len();
+        loop {
+            // The best move as `(gain, removed, added)`.
+            let mut best: Option<(usize, Option<usize>, usize)> = None;
+            for added in (0..n).filter(|&idx| !self.selected[idx]) {
+                let gain = self.contributions[added];
+                if self.used + self.weights[added] <= capacity
+                    && gain > 0
+                    && best.is_none_or(|(best_gain, _, _)| gain > best_gain)
+                {
+                    best = Some((gain, None, added));
+                }
+                for removed in (0..n).filter(|&idx| self.selected[idx]) {
+                    if self.used - self.weights[removed] + self.weights[added] > capacity {
+                        continue;
+                    }
+                    let gain = (self.contributions[added]
+                        - pair_profit(self.pairs, added, removed))
+                    .checked_sub(self.contributions[removed]);
+                    if let Some(gain) = gain.filter(|&gain| gain > 0) {
+                        if best.is_none_or(|(best_gain, _, _)| gain > best_gain) {
+                            best = Some((gain, Some(removed), added));
+                        }
+                    }
+                }
+            }
+            let Some((_, removed, added)) = best else {
+                break;
+            };
+            if let Some(removed) = removed {
+                self.remove(removed);
+            }
+            self.add(added);
+        }
+    }
+}
+
+// State of the depth-first branch and bound.
+struct Search<'a> {
+    order: &'a [usize],
+    // Position of every item in `order`.
+    rank: &'a [usize],
+    weights: &'a [usize],
+    pairs: &'a [Vec<(usize, usize)>],
+    // Value of every item plus its pair profits with the chosen items.
+    gains: Vec<usize>,
+    chosen: Vec<usize>,
+    best_value: usize,
+    best: Vec<usize>,
+}
+
+impl Search<'_> {
+    fn branch(&mut self, position: usize, remaining: usize, value: usize) {
+        if value > self.best_value {
+            self.best_value = value;
+            self.best = self.chosen.clone();
+        }
+        if position == self.order.len()
+            || value + self.bound(position, remaining) <= self.best_value
+        {
+            return;
+        }
+
+        let idx = self.order[position];
+        if self.weights[idx] <= remaining {
+            self.chosen.push(idx);
+            for &(partner, profit) in &self.pairs[idx] {
+                self.gains[partner] += profit;
+            }
+            self.branch(
+                position + 1,
+                remaining - self.weights[idx],
+                value + self.gains[idx],
+            );
+            for &(partner, profit) in &self.pairs[idx] {
+                self.gains[partner] -= profit;
+            }
+            self.chosen.pop();
+        }
+        self.branch(position + 1, remaining, value);
+    }
+
+    // Upper bound on the profit still obtainable from `order[position..]`.
+    //
+    // Every undecided item is credited with its gain plus all its pair
+    // profits with other undecided items, and the fractional knapsack bound
+    // is taken over these optimistic values.
+    fn bound(&self, position: usize, remaining: usize) -> usize {
+        let mut candidates: Vec<(usize, usize)> = self.order[position..]
+            .iter()
+            .filter(|&&idx| self.weights[idx] <= remaining)
+            .map(|&idx| {
+                let open: usize = self.pairs[idx]
+                    .iter()
+                    .filter(|&&(partner, _)| self.rank[partner] >= position)
+                    .map(|&(_, profit)| profit)
+                    .sum();
+                (self.weights[idx], self.gains[idx] + open)
+            })
+            .collect();
+        candidates.sort_by(|a, b| density(b.1, b.0).total_cmp(&density(a.1, a.0)));
+
+        let mut fractional = 0.0;
+        let mut room = remaining;
+        for (weight, profit) in candidates {
+            if weight <= room {
+                room -= weight;
+                fractional += profit as f64;
+            } else {
+                fractional += profit as f64 * room as f64 / weight as f64;
+                break;
+            }
+        }
+        fractional.floor() as usize
+    }
+}
@@ -0,0 +1,184 @@
+use crate::optimal::solve;
+use crate::traits::{Value, Weight};
+
+/// Defines the behavior for items whose weight may deviate from its nominal value.
+///
+/// The actual weight of the item lies in `[weight(), weight() + weight_deviation()]`.
+pub trait WeightDeviation {
+    /// Returns the maximum amount by which the item's weight can exceed its nominal weight.
+    fn weight_deviation(&self) -> usize;
+}
+
+/// An iterator that yields items of an optimal robust knapsack solution.
+///
+/// Under the budgeted uncertainty model of Bertsimas and Sim, at most `gamma`
+/// of the selected items take their upper weight `weight + weight_deviation`
+/// at the same time. A subset is feasible if it fits in the capacity for every
+/// such choice, i.e. its nominal weight plus its `gamma` largest deviations
+/// does not exceed the capacity.
+///
+/// The problem is solved exactly by its reduction to nominal knapsacks: for
+/// every threshold `theta` in `{0} ∪ {deviations}`, a nominal instance with
+/// weights `weight + max(0, deviation - theta)` and capacity
+/// `capacity - gamma * theta` is solved with the dynamic programming solver,
+/// and the best of these solutions is optimal for the robust problem.
+///
+/// # Examples
+///
+/// ```
+/// use knap::robust::{RobustKnapsackIterator, WeightDeviation};
+/// use knap::traits::{Value, Weight};
+///
+/// #[derive(Clone, Debug, PartialEq)]
+/// struct Item {
+///     id: char,
+///     weight: usize,
+///     deviation: usize,
+///     value: usize,
+/// }
+///
+/// impl Weight for Item {
+///     fn weight(&self) -> usize {
+///         self.weight
+///     }
+/// }
+///
+/// impl WeightDeviation for Item {
+///     fn weight_deviation(&self) -> usize {
+///         self.deviation
+///     }
+/// }
+///
+/// impl Value for Item {
+///     fn value(&self) -> usize {
+///         self.value
+///     }
+/// }
+///
+/// let items = vec![
+///     Item { id: 'A', weight: 4, deviation: 4, value: 10 },
+///     Item { id: 'B', weight: 4, deviation: 0, value: 7 },
+///     Item { id: 'C', weight: 4, deviation: 1, value: 6 },
+/// ];
+///
+/// // Nominally A and B fit, but A may grow to 8.
+/// let ids: Vec<char> = RobustKnapsackIterator::new(items, 9, 1).map(|item| item.id).collect();
+/// assert_eq!(ids, vec!['B', 'C']);
+/// ```
+#[derive(Debug)]
+pub struct RobustKnapsackIterator<T>
+where
+    T: Weight + WeightDeviation + Value + Clone,
+{
+    items: Vec<T>,
+    capacity: usize,
+    gamma: usize,
+    solution_items: Vec<T>,
+    current_index: usize,
+    // Ensures the nominal problems are solved only once.
+    computed: bool,
+}
+
+impl<T> RobustKnapsackIterator<T>
+where
+    T: Weight + WeightDeviation + Value + Clone,
+{
+    /// Creates a new `RobustKnapsackIterator`.
+    ///
+    /// # Arguments
+    ///
+    /// * `input_items`: An iterator over items that implement `Weight`, `WeightDeviation`, `Value`, and `Clone`.
+    /// * `capacity`: The maximum capacity of the knapsack.
+    /// * `gamma`: The maximum number of items whose weight deviates simultaneously.
+    pub fn new(input_items: impl IntoIterator<Item = T>, capacity: usize, gamma: usize) -> Self {
+        RobustKnapsackIterator {
+            items: input_items.into_iter().collect(),
+            capacity,
+            gamma,
+            solution_items: Vec::new(),
+            current_index: 0,
+            computed: false,
+        }
+    }
+
+    fn compute_solution(&mut self) {
+        let mut thetas: Vec<usize> = self
+            .items
+            .iter()
+            .map(WeightDeviation::weight_deviation)
+            .collect();
+        thetas.push(0);
+        thetas.sort_unstable();
+        thetas.dedup();
+
+        let mut best: Option<(usize, Vec<usize>)> = None;
+        for theta in thetas {
+            let Some(capacity) = self
+                .gamma
+                .checked_mul(theta)
+                .and_then(|reserved| self.capacity.checked_sub(reserved))
+            else {
+                break;
+            };
+            let entries: Vec<(usize, usize)> = self
+                .items
+                .iter()
+                .map(|item| {
+                    let excess = item.weight_deviation().saturating_sub(theta);
+                    (item.weight() + excess, item.value())
+                })
+                .collect();
+            let (value, indices) = solve(&entries, capacity);
+            if best
+                .as_ref()
+                .is_none_or(|(best_value, _)| value > *best_value)
+            {
+                best = Some((value, indices));
+            }
+        }
+
+        let indices = best.map(|(_, indices)| indices).unwrap_or_default();
+        self.solution_items = indices
+            .into_iter()
+            .map(|idx| self.items[idx].clone())
+            .collect();
+    }
+}
+
+impl<T> Iterator for RobustKnapsackIterator<T>
+where
+    T: Weight + WeightDeviation + Value + Clone,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.computed {
+            self.compute_solution();
+            self.computed = true;
+        }
+
+        if self.current_index < self.solution_items.len() {
+            let item = self.solution_items[self.current_index].clone();
+            self.current_index += 1;
+            Some(item)
+        } else {
+            None
+        }
+    }
+}
+
+/// Returns the worst-case weight of `items` when at most `gamma` of them deviate.
+///
+/// A selection is feasible for `RobustKnapsackIterator` if its worst-case
+/// weight does not exceed the capacity.
+pub fn worst_case_weight<T>(items: &[T], gamma: usize) -> usize
+where
+    T: Weight + WeightDeviation,
+{
+    let mut deviations: Vec<usize> = items
+        .iter()
+        .map(WeightDeviation::weight_deviation)
+        .collect();
+    deviations.sort_unstable_by(|a, b| b.cmp(a));
+    items.iter().map(Weight::weight).sum::<usize>() + deviations.iter().take(gamma).sum::<usize>()
+}
@@ -0,0 +1,57 @@
+use crate::profile::CapacityProfile;
+
+/// A reusable solver for answering many knapsack queries on the same items.
+///
+/// Creating a `KnapsackIterator` for every query rebuilds the dynamic
+/// programming table each time. A session builds it once, up to a maximum
+/// capacity, and then answers value queries in constant time and subset
+/// queries in O(n) for any capacity up to that maximum. It is the same type as
+/// `CapacityProfile`, named after this use.
+///
+/// # Examples
+///
+/// ```
+/// use knap::session::KnapsackSession;
+/// use knap::traits::{Value, Weight};
+///
+/// #[derive(Clone, Debug, PartialEq)]
+/// struct Item {
+///     id: char,
+///     weight: usize,
+///     value: usize,
+/// }
+///
+/// impl Weight for Item {
+///     fn weight(&self) -> usize {
+///         self.weight
+///     }
+/// }
+///
+/// impl Value for Item {
+///     fn value(&self) -> usize {
+///         self.value
+///     }
+/// }
+///
+/// let items = vec![
+///     Item { id: 'A', weight: 10, value: 60 },
+///     Item { id: 'B', weight: 20, value: 100 },
+///     Item { id: 'C', weight: 30, value: 120 },
+/// ];
+///
+/// let session = KnapsackSession::new(items, 100);
+///
+/// assert_eq!(session.value(50), Some(220));
+/// assert_eq!(session.value(15), Some(60));
+///
+/// let ids: Vec<char> = session.solution(50).unwrap().iter().map(|item| item.id).collect();
+/// assert_eq!(ids, vec!['B', 'C']);
+///
+/// let values: Vec<Option<usize>> = session
+///     .solutions([15, 101])
+///     .into_iter()
+///     .map(|solution| solution.map(|items| items.iter().map(|item| item.value).sum()))
+///     .collect();
+/// assert_eq!(values, vec![Some(60), None]);
+/// ```
+pub type KnapsackSession<T> = CapacityProfile<T>;
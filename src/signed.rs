@@ -0,0 +1,188 @@
+use crate::greedy::GreedyKnapsackIterator;
+use crate::optimal::{IndexedEntry, KnapsackIterator};
+use crate::traits::{SignedValue, SignedWeight};
+
+/// The algorithm used by `SignedKnapsackIterator` on the reduced instance.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SignedMethod {
+    /// Solves the reduced instance with `GreedyKnapsackIterator`.
+    Greedy,
+    /// Solves the reduced instance exactly with `KnapsackIterator`.
+    Optimal,
+}
+
+/// An iterator that yields items of a knapsack solution with signed weights
+/// and values.
+///
+/// Items may free up capacity (negative weight) or carry a negative value,
+/// and the capacity itself may be negative. The instance is reduced to a
+/// standard knapsack solved by the solver chosen with `SignedMethod`:
+///
+/// - items with a non-positive weight and a non-negative value are always
+///   selected, and items with a non-negative weight and a non-positive value
+///   never are (items with zero weight and value are left out);
+/// - items with a negative weight and a negative value are selected up front
+///   and replaced by a complemented item of weight `-weight` and value
+///   `-value`, whose selection stands for leaving the original item out;
+/// - the capacity grows by the capacity freed by all items selected up front.
+///
+/// With `SignedMethod::Optimal`, the running time is proportional to the
+/// adjusted capacity. If the adjusted capacity is negative, no selection fits
+/// and the iterator yields nothing; use `is_feasible` to tell this apart from
+/// an empty solution. Items are yielded in input order.
+///
+/// # Examples
+///
+/// ```
+/// use knap::signed::{SignedKnapsackIterator, SignedMethod};
+/// use knap::traits::{SignedValue, SignedWeight};
+///
+/// #[derive(Clone, Debug, PartialEq)]
+/// struct Move {
+///     id: char,
+///     space: i64,
+///     profit: i64,
+/// }
+///
+/// impl SignedWeight for Move {
+///     fn signed_weight(&self) -> i64 {
+///         self.space
+///     }
+/// }
+///
+/// impl SignedValue for Move {
+///     fn signed_value(&self) -> i64 {
+///         self.profit
+///     }
+/// }
+///
+/// let moves = vec![
+///     Move { id: 'A', space: 4, profit: 10 },
+///     Move { id: 'B', space: -3, profit: -2 },
+///     Move { id: 'C', space: 3, profit: 7 },
+/// ];
+///
+/// // Only A fits, unless B frees up room for C at a small loss.
+/// let ids: Vec<char> = SignedKnapsackIterator::new(moves, 4, SignedMethod::Optimal)
+///     .map(|item| item.id)
+///     .collect();
+/// assert_eq!(ids, vec!['A', 'B', 'C']);
+/// ```
+#[derive(Debug)]
+pub struct SignedKnapsackIterator<T>
+where
+    T: SignedWeight + SignedValue + Clone,
+{
+    items: Vec<T>,
+    capacity: i64,
+    method: SignedMethod,
+    solution_items: Vec<T>,
+    feasible: bool,
+    current_index: usize,
+    // Ensures DP is run only once.
+    computed: bool,
+}
+
+impl<T> SignedKnapsackIterator<T>
+where
+    T: SignedWeight + SignedValue + Clone,
+{
+    /// Creates a new `SignedKnapsackIterator`.
+    ///
+    /// # Arguments
+    ///
+    /// * `input_items`: An iterator over items that implement `SignedWeight`, `SignedValue`, and `Clone`.
+    /// * `capacity`: The maximum total weight of the selected items.
+    /// * `method`: The algorithm used to solve the reduced instance.
+    pub fn new(
+        input_items: impl IntoIterator<Item = T>,
+        capacity: i64,
+        method: SignedMethod,
+    ) -> Self {
+        SignedKnapsackIterator {
+            items: input_items.into_iter().collect(),
+            capacity,
+            method,
+            solution_items: Vec::new(),
+            feasible: false,
+            current_index: 0,
+            computed: false,
+        }
+    }
+
+    /// Returns `true` if some selection of items fits in the capacity.
+    pub fn is_feasible(&mut self) -> bool {
+        if !self.computed {
+            self.compute_solution();
+        }
+        self.feasible
+    }
+
+    fn compute_solution(&mut self) {
+        self.computed = true;
+        let mut selected = vec![false; self.items.len()];
+        let mut capacity = self.capacity;
+        let mut entries = Vec::new();
+        for (idx, item) in self.items.iter().enumerate() {
+            let weight = item.signed_weight();
+            let value = item.signed_value();
+            if weight <= 0 && value >= 0 && (weight, value) != (0, 0) {
+                selected[idx] = true;
+                capacity -= weight;
+            } else if weight < 0 && value < 0 {
+                selected[idx] = true;
+                capacity -= weight;
+                entries.push(IndexedEntry {
+                    index: idx,
+                    weight: weight.unsigned_abs() as usize,
+                    value: value.unsigned_abs() as usize,
+                });
+            } else if weight > 0 && value > 0 {
+                entries.push(IndexedEntry {
+                    index: idx,
+                    weight: weight as usize,
+                    value: value as usize,
+                });
+            }
+        }
+        if capacity < 0 {
+            return;
+        }
+
+        self.feasible = true;
+        let capacity = capacity as usize;
+        let chosen: Vec<IndexedEntry> = match self.method {
+            SignedMethod::Greedy => GreedyKnapsackIterator::new(entries, capacity).collect(),
+            SignedMethod::Optimal => KnapsackIterator::new(entries, capacity).collect(),
+        };
+        for entry in chosen {
+            // Selecting a complemented item leaves the original one out.
+            selected[entry.index] = !selected[entry.index];
+        }
+        self.solution_items = (0..self.items.len())
+            .filter(|&idx| selected[idx])
+            .map(|idx| self.items[idx].clone())
+            .collect();
+    }
+}
+
+impl<T> Iterator for SignedKnapsackIterator<T>
+where
+    T: SignedWeight + SignedValue + Clone,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.computed {
+            self.compute_solution();
+        }
+
+        if self.current_index < self.solution_items.len() {
+            let item = self.solution_items[self.current_index].clone();
+            self.current_index += 1;
+            Some(item)
+        } else {
+            None
+        }
+    }
+}
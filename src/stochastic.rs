@@ -0,0 +1,297 @@
+use crate::optimal::{backtrack, dp_table, IndexedEntry, KBestKnapsackIterator};
+use crate::traits::Value;
+
+/// The probability distribution of an item's weight.
+#[derive(Debug, Clone, PartialEq)]
+pub enum WeightDistribution {
+    /// A finite support of `(weight, probability)` pairs.
+    ///
+    /// Probabilities should sum to one; any shortfall is assigned to the last weight.
+    Discrete(Vec<(usize, f64)>),
+    /// A normal distribution, truncated at zero and rounded to the nearest integer.
+    Normal { mean: f64, std_dev: f64 },
+}
+
+impl WeightDistribution {
+    /// Returns the expected weight.
+    pub fn mean(&self) -> f64 {
+        match self {
+            WeightDistribution::Discrete(support) => {
+                support.iter().map(|&(w, p)| w as f64 * p).sum()
+            }
+            WeightDistribution::Normal { mean, .. } => *mean,
+        }
+    }
+
+    fn sample(&self, rng: &mut SplitMix64) -> usize {
+        match self {
+            WeightDistribution::Discrete(support) => {
+                let u = rng.next_f64();
+                let mut cumulative = 0.0;
+                for &(weight, probability) in support {
+                    cumulative += probability;
+                    if u < cumulative {
+                        return weight;
+                    }
+                }
+                support.last().map_or(0, |&(weight, _)| weight)
+            }
+            WeightDistribution::Normal { mean, std_dev } => {
+                // Box–Muller transform.
+                let u1 = 1.0 - rng.next_f64();
+                let u2 = rng.next_f64();
+                let z = (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos();
+                (mean + std_dev * z).round().max(0.0) as usize
+            }
+        }
+    }
+}
+
+/// Defines the behavior for items whose weight is a random variable.
+pub trait StochasticWeight {
+    /// Returns the distribution of the item's weight.
+    fn weight_distribution(&self) -> WeightDistribution;
+}
+
+/// An iterator over a solution of the chance-constrained stochastic knapsack.
+///
+/// Item weights are random, and a subset is feasible if its total weight fits
+/// within the capacity with probability at least `confidence`. The solver uses
+/// sample-average approximation: it draws a fixed set of weight scenarios and
+/// looks for the most valuable subset that fits in at least a `confidence`
+/// fraction of them.
+///
+/// Any such subset fits when each item takes its smallest sampled weight, so
+/// candidates are enumerated in non-increasing value with
+/// `KBestKnapsackIterator` on those weights, and the first one passing the
+/// scenario test is an optimal solution of the sampled problem. If no
+/// candidate passes within the candidate limit, the solver falls back to
+/// solving the problem on expected weights for tighter and tighter capacities
+/// until the solution passes the test.
+///
+/// Sampling is seeded, so results are reproducible.
+///
+/// # Examples
+///
+/// ```
+/// use knap::stochastic::{StochasticKnapsackIterator, StochasticWeight, WeightDistribution};
+/// use knap::traits::Value;
+///
+/// #[derive(Clone, Debug, PartialEq)]
+/// struct Job {
+///     id: char,
+///     duration: WeightDistribution,
+///     value: usize,
+/// }
+///
+/// impl StochasticWeight for Job {
+///     fn weight_distribution(&self) -> WeightDistribution {
+///         self.duration.clone()
+///     }
+/// }
+///
+/// impl Value for Job {
+///     fn value(&self) -> usize {
+///         self.value
+///     }
+/// }
+///
+/// let jobs = vec![
+///     Job { id: 'A', duration: WeightDistribution::Discrete(vec![(4, 0.5), (6, 0.5)]), value: 10 },
+///     Job { id: 'B', duration: WeightDistribution::Discrete(vec![(5, 1.0)]), value: 9 },
+///     Job { id: 'C', duration: WeightDistribution::Discrete(vec![(5, 1.0)]), value: 8 },
+/// ];
+///
+/// // A and B fit only half of the time, B and C always fit.
+/// let ids: Vec<char> = StochasticKnapsackIterator::new(jobs, 10, 0.9)
+///     .map(|job| job.id)
+///     .collect();
+/// assert_eq!(ids, vec!['B', 'C']);
+/// ```
+#[derive(Debug)]
+pub struct StochasticKnapsackIterator<T>
+where
+    T: StochasticWeight + Value + Clone,
+{
+    items: Vec<T>,
+    capacity: usize,
+    confidence: f64,
+    samples: usize,
+    seed: u64,
+    max_candidates: usize,
+    solution_items: Vec<T>,
+    probability: f64,
+    current_index: usize,
+    // Ensures the approximation is run only once.
+    computed: bool,
+}
+
+impl<T> StochasticKnapsackIterator<T>
+where
+    T: StochasticWeight + Value + Clone,
+{
+    /// Creates a new `StochasticKnapsackIterator` using 1000 scenarios.
+    ///
+    /// # Arguments
+    ///
+    /// * `input_items`: An iterator over items that implement `StochasticWeight`, `Value`, and `Clone`.
+    /// * `capacity`: The maximum capacity of the knapsack.
+    /// * `confidence`: The required probability, in `[0, 1]`, that the solution fits.
+    pub fn new(input_items: impl IntoIterator<Item = T>, capacity: usize, confidence: f64) -> Self {
+        StochasticKnapsackIterator {
+            items: input_items.into_iter().collect(),
+            capacity,
+            confidence,
+            samples: 1000,
+            seed: 0x5EED,
+            max_candidates: 10_000,
+            solution_items: Vec::new(),
+            probability: 1.0,
+            current_index: 0,
+            computed: false,
+        }
+    }
+
+    /// Sets the number of sampled scenarios.
+    pub fn with_samples(mut self, samples: usize) -> Self {
+        self.samples = samples;
+        self
+    }
+
+    /// Sets the seed of the scenario generator.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Sets how many candidates are enumerated before falling back to the heuristic.
+    pub fn with_max_candidates(mut self, max_candidates: usize) -> Self {
+        self.max_candidates = max_candidates;
+        self
+    }
+
+    /// Returns the empirical probability that the solution fits, over the sampled scenarios.
+    pub fn estimated_probability(&mut self) -> f64 {
+        if !self.computed {
+            self.compute_solution();
+        }
+        self.probability
+    }
+
+    fn compute_solution(&mut self) {
+        self.computed = true;
+        let distributions: Vec<WeightDistribution> = self
+            .items
+            .iter()
+            .map(StochasticWeight::weight_distribution)
+            .collect();
+
+        let mut rng = SplitMix64(self.seed);
+        let scenarios: Vec<Vec<usize>> = (0..self.samples)
+            .map(|_| distributions.iter().map(|d| d.sample(&mut rng)).collect())
+            .collect();
+        let probability_of = |indices: &[usize]| {
+            if scenarios.is_empty() {
+                return 1.0;
+            }
+            let fitting = scenarios
+                .iter()
+                .filter(|scenario| {
+                    indices.iter().map(|&idx| scenario[idx]).sum::<usize>() <= self.capacity
+                })
+                .count();
+            fitting as f64 / scenarios.len() as f64
+        };
+
+        let relaxed: Vec<IndexedEntry> = self
+            .items
+            .iter()
+            .enumerate()
+            .map(|(index, item)| IndexedEntry {
+                index,
+                weight: scenarios
+                    .iter()
+                    .map(|scenario| scenario[index])
+                    .min()
+                    .unwrap_or(0),
+                value: item.value(),
+            })
+            .collect();
+        let mut best = KBestKnapsackIterator::new(relaxed, self.capacity, self.max_candidates)
+            .map(|candidate| {
+                candidate
+                    .iter()
+                    .map(|entry| entry.index)
+                    .collect::<Vec<usize>>()
+            })
+            .map(|indices| {
+                let probability = probability_of(&indices);
+                (indices, probability)
+            })
+            .find(|(_, probability)| *probability >= self.confidence);
+
+        if best.is_none() {
+            let entries: Vec<(usize, usize)> = distributions
+                .iter()
+                .zip(&self.items)
+                .map(|(distribution, item)| (distribution.mean().ceil() as usize, item.value()))
+                .collect();
+            let dp = dp_table(&entries, self.capacity);
+            best = (0..=self.capacity)
+                .rev()
+                .map(|capacity| backtrack(&dp, &entries, capacity))
+                .map(|indices| {
+                    let probability = probability_of(&indices);
+                    (indices, probability)
+                })
+                .find(|(_, probability)| *probability >= self.confidence);
+        }
+
+        let (indices, probability) = best.unwrap_or_else(|| (Vec::new(), 1.0));
+        self.probability = probability;
+        self.solution_items = indices
+            .into_iter()
+            .map(|idx| self.items[idx].clone())
+            .collect();
+    }
+}
+
+impl<T> Iterator for StochasticKnapsackIterator<T>
+where
+    T: StochasticWeight + Value + Clone,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.computed {
+            self.compute_solution();
+        }
+
+        if self.current_index < self.solution_items.len() {
+            let item = self.solution_items[self.current_index].clone();
+            self.current_index += 1;
+            Some(item)
+        } else {
+            None
+        }
+    }
+}
+
+// A small SplitMix64 generator, enough for reproducible scenario sampling.
+#[derive(Debug)]
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    // Uniform sample in [0, 1).
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
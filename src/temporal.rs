@@ -0,0 +1,343 @@
+use crate::traits::{Value, Weight};
+
+/// Defines the behavior for items that occupy capacity during a time interval.
+///
+/// The interval is half-open: the item occupies its weight at every instant
+/// `t` with `start() <= t < end()`. An item with `end() <= start()` never
+/// occupies any capacity.
+pub trait Interval {
+    /// Returns the first instant at which the item occupies capacity.
+    fn start(&self) -> usize;
+
+    /// Returns the first instant after the item stops occupying capacity.
+    fn end(&self) -> usize;
+}
+
+/// The algorithm used by `TemporalKnapsackIterator`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TemporalMethod {
+    /// Takes items by decreasing value per unit of weight and duration
+    /// whenever they fit at every instant of their interval.
+    Greedy,
+    /// Exact depth-first branch and bound, starting from the greedy solution.
+    /// The running time is exponential in the worst case, so it is meant for
+    /// small instances.
+    Exact,
+}
+
+/// An iterator that yields items of a temporal knapsack solution.
+///
+/// Every item occupies its weight only during its `Interval`, and a selection
+/// is feasible if the total weight of the selected items active at any instant
+/// never exceeds the capacity. The total value is maximized. Items are yielded
+/// in input order.
+///
+/// The exact search bounds every node by the smaller of two relaxations: the
+/// total value of the remaining items that still fit, and the fractional
+/// knapsack over capacity-time, where each item consumes its weight times its
+/// duration out of the free capacity summed over time.
+///
+/// # Examples
+///
+/// ```
+/// use knap::temporal::{Interval, TemporalKnapsackIterator, TemporalMethod};
+/// use knap::traits::{Value, Weight};
+///
+/// #[derive(Clone, Debug, PartialEq)]
+/// struct Booking {
+///     id: char,
+///     rooms: usize,
+///     from: usize,
+///     to: usize,
+///     price: usize,
+/// }
+///
+/// impl Weight for Booking {
+///     fn weight(&self) -> usize {
+///         self.rooms
+///     }
+/// }
+///
+/// impl Value for Booking {
+///     fn value(&self) -> usize {
+///         self.price
+///     }
+/// }
+///
+/// impl Interval for Booking {
+///     fn start(&self) -> usize {
+///         self.from
+///     }
+///
+///     fn end(&self) -> usize {
+///         self.to
+///     }
+/// }
+///
+/// let bookings = vec![
+///     Booking { id: 'A', rooms: 2, from: 0, to: 3, price: 6 },
+///     Booking { id: 'B', rooms: 2, from: 3, to: 5, price: 5 },
+///     Booking { id: 'C', rooms: 3, from: 2, to: 4, price: 8 },
+/// ];
+///
+/// // A and B never overlap, but C overlaps both of them.
+/// let ids: Vec<char> = TemporalKnapsackIterator::new(bookings, 3, TemporalMethod::Exact)
+///     .map(|booking| booking.id)
+///     .collect();
+/// assert_eq!(ids, vec!['A', 'B']);
+/// ```
+#[derive(Debug)]
+pub struct TemporalKnapsackIterator<T>
+where
+    T: Weight + Value + Interval + Clone,
+{
+    items: Vec<T>,
+    capacity: usize,
+    method: TemporalMethod,
+    solution_items: Vec<T>,
+    current_index: usize,
+    // Ensures the solution is computed only once.
+    computed: bool,
+}
+
+impl<T> TemporalKnapsackIterator<T>
+where
+    T: Weight + Value + Interval + Clone,
+{
+    /// Creates a new `TemporalKnapsackIterator`.
+    ///
+    /// # Arguments
+    ///
+    /// * `input_items`: An iterator over items that implement `Weight`, `Value`, `Interval`, and `Clone`.
+    /// * `capacity`: The capacity available at every instant.
+    /// * `method`: The algorithm used to compute the solution.
+    pub fn new(
+        input_items: impl IntoIterator<Item = T>,
+        capacity: usize,
+        method: TemporalMethod,
+    ) -> Self {
+        TemporalKnapsackIterator {
+            items: input_items.into_iter().collect(),
+            capacity,
+            method,
+            solution_items: Vec::new(),
+            current_index: 0,
+            computed: false,
+        }
+    }
+
+    fn compute_solution(&mut self) {
+        let timeline = Timeline::new(&self.items);
+        let weights: Vec<usize> = self.items.iter().map(Weight::weight).collect();
+        let values: Vec<usize> = self.items.iter().map(Value::value).collect();
+        // Capacity consumed over time by every item.
+        let footprints: Vec<usize> = (0..self.items.len())
+            .map(|idx| weights[idx] * timeline.duration(idx))
+            .collect();
+        let density = |idx: usize| match (footprints[idx], values[idx]) {
+            (0, 0) => -1.0,
+            (0, _) => f64::MAX,
+            (footprint, value) => value as f64 / footprint as f64,
+        };
+        let mut order: Vec<usize> = (0..self.items.len()).collect();
+        order.sort_by(|&a, &b| density(b).total_cmp(&density(a)).then(a.cmp(&b)));
+
+        let mut search = Search {
+            order: &order,
+            weights: &weights,
+            values: &values,
+            footprints: &footprints,
+            timeline: &timeline,
+            capacity: self.capacity,
+            usage: vec![0; timeline.segments()],
+            chosen: Vec::new(),
+            best_value: 0,
+            best: Vec::new(),
+        };
+        for &idx in &order {
+            if search.fits(idx) {
+                search.occupy(idx, true);
+                search.chosen.push(idx);
+            }
+        }
+        search.best_value = search.chosen.iter().map(|&idx| values[idx]).sum();
+        search.best = std::mem::take(&mut search.chosen);
+        if self.method == TemporalMethod::Exact {
+            search.usage = vec![0; timeline.segments()];
+            search.branch(0, 0);
+        }
+
+        let mut best = search.best;
+        best.sort_unstable();
+        self.solution_items = best
+            .into_iter()
+            .map(|idx| self.items[idx].clone())
+            .collect();
+    }
+}
+
+impl<T> Iterator for TemporalKnapsackIterator<T>
+where
+    T: Weight + Value + Interval + Clone,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.computed {
+            self.compute_solution();
+            self.computed = true;
+        }
+
+        if self.current_index < self.solution_items.len() {
+            let item = self.solution_items[self.current_index].clone();
+            self.current_index += 1;
+            Some(item)
+        } else {
+            None
+        }
+    }
+}
+
+/// Returns the largest total weight of `items` active at the same instant.
+///
+/// A selection is feasible for `TemporalKnapsackIterator` if its peak usage
+/// does not exceed the capacity.
+pub fn peak_usage<T>(items: &[T]) -> usize
+where
+    T: Weight + Interval,
+{
+    let timeline = Timeline::new(items);
+    let mut usage = vec![0; timeline.segments()];
+    for (item, &(first, last)) in items.iter().zip(&timeline.spans) {
+        for used in &mut usage[first..last] {
+            *used += item.weight();
+        }
+    }
+    usage.into_iter().max().unwrap_or(0)
+}
+
+// The time axis cut into segments at the interval endpoints, within which the
+// set of active items does not change.
+struct Timeline {
+    points: Vec<usize>,
+    // Range of segments covered by every item.
+    spans: Vec<(usize, usize)>,
+}
+
+impl Timeline {
+    fn new<T: Interval>(items: &[T]) -> Self {
+        let mut points: Vec<usize> = items
+            .iter()
+            .filter(|item| item.start() < item.end())
+            .flat_map(|item| [item.start(), item.end()])
+            .collect();
+        points.sort_unstable();
+        points.dedup();
+        let position = |t: usize| points.binary_search(&t).unwrap_or(0);
+        let spans = items
+            .iter()
+            .map(|item| {
+                if item.start() < item.end() {
+                    (position(item.start()), position(item.end()))
+                } else {
+                    (0, 0)
+                }
+            })
+            .collect();
+        Timeline { points, spans }
+    }
+
+    fn segments(&self) -> usize {
+        self.points.len().saturating_sub(1)
+    }
+
+    fn length(&self, segment: usize) -> usize {
+        self.points[segment + 1] - self.points[segment]
+    }
+
+    fn duration(&self, idx: usize) -> usize {
+        let (first, last) = self.spans[idx];
+        (first..last).map(|segment| self.length(segment)).sum()
+    }
+}
+
+// State of the depth-first branch and bound.
+struct Search<'a> {
+    // Items by decreasing value per unit of capacity-time.
+    order: &'a [usize],
+    weights: &'a [usize],
+    values: &'a [usize],
+    footprints: &'a [usize],
+    timeline: &'a Timeline,
+    capacity: usize,
+    // Capacity used in every segment.
+    usage: Vec<usize>,
+    chosen: Vec<usize>,
+    best_value: usize,
+    best: Vec<usize>,
+}
+
+impl Search<'_> {
+    fn fits(&self, idx: usize) -> bool {
+        let (first, last) = self.timeline.spans[idx];
+        self.usage[first..last]
+            .iter()
+            .all(|&used| used + self.weights[idx] <= self.capacity)
+    }
+
+    fn occupy(&mut self, idx: usize, taken: bool) {
+        let (first, last) = self.timeline.spans[idx];
+        for used in &mut self.usage[first..last] {
+            if taken {
+                *used += self.weights[idx];
+            } else {
+                *used -= self.weights[idx];
+            }
+        }
+    }
+
+    fn branch(&mut self, position: usize, value: usize) {
+        if value > self.best_value {
+            self.best_value = value;
+            self.best = self.chosen.clone();
+        }
+        if position == self.order.len() || value + self.bound(position) <= self.best_value {
+            return;
+        }
+
+        let idx = self.order[position];
+        if self.fits(idx) {
+            self.occupy(idx, true);
+            self.chosen.push(idx);
+            self.branch(position + 1, value + self.values[idx]);
+            self.chosen.pop();
+            self.occupy(idx, false);
+        }
+        self.branch(position + 1, value);
+    }
+
+    // Upper bound on the value still obtainable from `order[position..]`.
+    fn bound(&self, position: usize) -> usize {
+        let fitting: Vec<usize> = self.order[position..]
+            .iter()
+            .copied()
+            .filter(|&idx| self.fits(idx))
+            .collect();
+        let total: usize = fitting.iter().map(|&idx| self.values[idx]).sum();
+
+        let mut room: usize = (0..self.timeline.segments())
+            .map(|segment| (self.capacity - self.usage[segment]) * self.timeline.length(segment))
+            .sum();
+        let mut fractional = 0.0;
+        for idx in fitting {
+            if self.footprints[idx] <= room {
+                room -= self.footprints[idx];
+                fractional += self.values[idx] as f64;
+            } else {
+                fractional += self.values[idx] as f64 * room as f64 / self.footprints[idx] as f64;
+                break;
+            }
+        }
+        (fractional.floor() as usize).min(total)
+    }
+}
@@ -0,0 +1,111 @@
+use std::cmp::Ordering;
+use std::fmt;
+
+use crate::traits::Weight;
+
+/// A policy deciding between solutions that are equally good for a solver.
+///
+/// The optimal solver (`KnapsackIterator`) uses the policy to pick one subset
+/// among all subsets of maximum value. The greedy solver
+/// (`GreedyKnapsackIterator`) uses it to order items that have the same
+/// value-to-weight ratio. Any tie left by the policy is resolved in favor of
+/// the lowest input indices, so results are fully deterministic.
+///
+/// # Examples
+///
+/// ```
+/// use knap::traits::{Value, Weight};
+/// use knap::{KnapsackIterator, TieBreak};
+///
+/// #[derive(Clone, Debug, PartialEq)]
+/// struct Item {
+///     id: char,
+///     weight: usize,
+///     value: usize,
+/// }
+///
+/// impl Weight for Item {
+///     fn weight(&self) -> usize {
+///         self.weight
+///     }
+/// }
+///
+/// impl Value for Item {
+///     fn value(&self) -> usize {
+///         self.value
+///     }
+/// }
+///
+/// let items = vec![
+///     Item { id: 'A', weight: 2, value: 3 },
+///     Item { id: 'B', weight: 2, value: 3 },
+///     Item { id: 'C', weight: 4, value: 6 },
+/// ];
+///
+/// let ids: Vec<char> = KnapsackIterator::new(items, 4)
+///     .with_tie_break(TieBreak::FewestItems)
+///     .map(|item| item.id)
+///     .collect();
+///
+/// assert_eq!(ids, vec!['C']);
+/// ```
+pub enum TieBreak<T> {
+    /// Prefer the solution with the fewest items.
+    ///
+    /// The greedy solver prefers the heavier of two equally dense items.
+    FewestItems,
+    /// Prefer the solution with the lightest total weight.
+    ///
+    /// The greedy solver prefers the lighter of two equally dense items.
+    LightestWeight,
+    /// Prefer the solution whose sorted input indices are lexicographically smallest.
+    ///
+    /// The greedy solver prefers the item that comes first in the input.
+    LowestIndices,
+    /// Prefer the solution that compares as `Ordering::Less` under the given function.
+    ///
+    /// The optimal solver streams every optimal subset through the comparator
+    /// and keeps only the best one so far. Its running time therefore grows
+    /// with the number of optimal solutions, which can be exponential in the
+    /// number of interchangeable items. The greedy solver compares single-item
+    /// slices.
+    Custom(fn(&[T], &[T]) -> Ordering),
+}
+
+impl<T> TieBreak<T>
+where
+    T: Weight,
+{
+    // Orders two equally dense items for the greedy solver; the preferred
+    // item compares as `Ordering::Less`.
+    pub(crate) fn compare_items(&self, a: (usize, &T), b: (usize, &T)) -> Ordering {
+        let policy_cmp = match self {
+            TieBreak::FewestItems => b.1.weight().cmp(&a.1.weight()),
+            TieBreak::LightestWeight => a.1.weight().cmp(&b.1.weight()),
+            TieBreak::LowestIndices => Ordering::Equal,
+            TieBreak::Custom(compare) => {
+                compare(std::slice::from_ref(a.1), std::slice::from_ref(b.1))
+            }
+        };
+        policy_cmp.then(a.0.cmp(&b.0))
+    }
+}
+
+impl<T> Clone for TieBreak<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for TieBreak<T> {}
+
+impl<T> fmt::Debug for TieBreak<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TieBreak::FewestItems => write!(f, "FewestItems"),
+            TieBreak::LightestWeight => write!(f, "LightestWeight"),
+            TieBreak::LowestIndices => write!(f, "LowestIndices"),
+            TieBreak::Custom(_) => write!(f, "Custom(..)"),
+        }
+    }
+}
@@ -20,6 +20,55 @@ pub trait Value {
     fn value(&self) -> usize;
 }
 
+/// Defines the behavior for items that have several values.
+///
+/// This trait is used by multi-objective solvers, where each item contributes
+/// to several objectives at once (e.g. profit and risk).
+pub trait Values {
+    /// Returns the values of the item, one per objective.
+    ///
+    /// Every item must return the same number of values, and each value
+    /// must be a non-negative integer.
+    fn values(&self) -> Vec<usize>;
+}
+
+/// Defines the behavior for items whose weight may be negative.
+///
+/// A negative weight frees up capacity, e.g. a returned item. Every type
+/// implementing `Weight` implements this trait as well.
+pub trait SignedWeight {
+    /// Returns the weight of the item.
+    fn signed_weight(&self) -> i64;
+}
+
+// Blanket implementation of `SignedWeight` for any type with a `Weight`.
+impl<T> SignedWeight for T
+where
+    T: Weight,
+{
+    fn signed_weight(&self) -> i64 {
+        self.weight() as i64
+    }
+}
+
+/// Defines the behavior for items whose value may be negative.
+///
+/// Every type implementing `Value` implements this trait as well.
+pub trait SignedValue {
+    /// Returns the value of the item.
+    fn signed_value(&self) -> i64;
+}
+
+// Blanket implementation of `SignedValue` for any type with a `Value`.
+impl<T> SignedValue for T
+where
+    T: Value,
+{
+    fn signed_value(&self) -> i64 {
+        self.value() as i64
+    }
+}
+
 /// An extension trait to easily convert an iterator into a `KnapsackIterator`.
 ///
 /// This trait provides a convenient way to create an optimal knapsack solver
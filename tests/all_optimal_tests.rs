@@ -0,0 +1,101 @@
+use knap::optimal::AllOptimalSolutionsIterator;
+use knap::traits::{Value, Weight};
+use knap::KnapsackIterator;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Item {
+    pub id: String,
+    pub weight: usize,
+    pub value: usize,
+}
+
+impl Item {
+    fn new(id: &str, weight: usize, value: usize) -> Self {
+        Item {
+            id: id.to_string(),
+            weight,
+            value,
+        }
+    }
+}
+
+impl Weight for Item {
+    fn weight(&self) -> usize {
+        self.weight
+    }
+}
+
+impl Value for Item {
+    fn value(&self) -> usize {
+        self.value
+    }
+}
+
+fn ids(solution: &[Item]) -> Vec<&str> {
+    solution.iter().map(|item| item.id.as_str()).collect()
+}
+
+#[test]
+fn test_unique_optimum() {
+    let items = vec![
+        Item::new("A", 10, 60),
+        Item::new("B", 20, 100),
+        Item::new("C", 30, 120),
+    ];
+    let solutions: Vec<Vec<Item>> = AllOptimalSolutionsIterator::new(items, 50).collect();
+    assert_eq!(solutions.len(), 1);
+    assert_eq!(ids(&solutions[0]), vec!["B", "C"]);
+}
+
+#[test]
+fn test_all_ties_enumerated() {
+    let items = vec![
+        Item::new("A", 1, 1),
+        Item::new("B", 1, 1),
+        Item::new("C", 1, 1),
+        Item::new("D", 2, 2),
+    ];
+    let iter = AllOptimalSolutionsIterator::new(items, 2);
+    assert_eq!(iter.optimal_value(), 2);
+
+    let mut solutions: Vec<Vec<String>> = iter
+        .map(|solution| solution.into_iter().map(|item| item.id).collect())
+        .collect();
+    solutions.sort();
+    assert_eq!(
+        solutions,
+        vec![vec!["A", "B"], vec!["A", "C"], vec!["B", "C"], vec!["D"]]
+    );
+}
+
+#[test]
+fn test_first_solution_matches_knapsack_iterator() {
+    let items = vec![
+        Item::new("item1", 2, 3),
+        Item::new("item2", 3, 4),
+        Item::new("item3", 4, 5),
+        Item::new("item4", 5, 6),
+        Item::new("item5", 7, 9),
+    ];
+    let capacity = 7;
+    let expected: Vec<Item> = KnapsackIterator::new(items.clone(), capacity).collect();
+    let first = AllOptimalSolutionsIterator::new(items, capacity).next();
+    assert_eq!(first, Some(expected));
+}
+
+#[test]
+fn test_zero_value_items_double_solutions() {
+    let items = vec![Item::new("valuable", 5, 10), Item::new("zero_val", 2, 0)];
+    let solutions: Vec<Vec<Item>> = AllOptimalSolutionsIterator::new(items, 7).collect();
+    assert_eq!(solutions.len(), 2);
+    assert!(solutions
+        .iter()
+        .all(|s| s.iter().map(|i| i.value).sum::<usize>() == 10));
+}
+
+#[test]
+fn test_empty_items_yield_empty_solution() {
+    let items: Vec<Item> = Vec::new();
+    let solutions: Vec<Vec<Item>> = AllOptimalSolutionsIterator::new(items, 10).collect();
+    assert_eq!(solutions, vec![Vec::<Item>::new()]);
+}
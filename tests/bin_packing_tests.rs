@@ -0,0 +1,167 @@
+use knap::bin_packing::{BinPacking, BinPackingError, BinPackingMethod, Packing};
+use knap::traits::Weight;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Item {
+    pub id: usize,
+    pub weight: usize,
+}
+
+impl Weight for Item {
+    fn weight(&self) -> usize {
+        self.weight
+    }
+}
+
+fn items(weights: &[usize]) -> Vec<Item> {
+    weights
+        .iter()
+        .enumerate()
+        .map(|(id, &weight)| Item { id, weight })
+        .collect()
+}
+
+fn ids(packing: &Packing<Item>) -> Vec<Vec<usize>> {
+    packing
+        .bins
+        .iter()
+        .map(|bin| bin.iter().map(|item| item.id).collect())
+        .collect()
+}
+
+fn assert_valid(packing: &Packing<Item>, count: usize, capacity: usize) {
+    let mut seen: Vec<usize> = packing.bins.iter().flatten().map(|item| item.id).collect();
+    seen.sort_unstable();
+    assert_eq!(seen, (0..count).collect::<Vec<usize>>());
+    for bin in &packing.bins {
+        assert!(!bin.is_empty());
+        assert!(bin.iter().map(|item| item.weight).sum::<usize>() <= capacity);
+    }
+}
+
+// Minimum number of bins, by trying every assignment of items to bins.
+fn brute_force(weights: &[usize], capacity: usize) -> usize {
+    fn place(weights: &[usize], loads: &mut Vec<usize>, capacity: usize, best: &mut usize) {
+        let Some((&weight, rest)) = weights.split_first() else {
+            *best = (*best).min(loads.len());
+            return;
+        };
+        for bin in 0..loads.len() {
+            if loads[bin] + weight <= capacity {
+                loads[bin] += weight;
+                place(rest, loads, capacity, best);
+                loads[bin] -= weight;
+            }
+        }
+        loads.push(weight);
+        place(rest, loads, capacity, best);
+        loads.pop();
+    }
+    let mut best = usize::MAX;
+    place(weights, &mut Vec::new(), capacity, &mut best);
+    best
+}
+
+#[test]
+fn test_first_fit_and_best_fit_differ() {
+    let solver = BinPacking::new(items(&[5, 7, 3, 2]), 10).unwrap();
+    assert_eq!(
+        ids(&solver.pack(BinPackingMethod::FirstFit)),
+        vec![vec![0, 2, 3], vec![1]]
+    );
+    assert_eq!(
+        ids(&solver.pack(BinPackingMethod::BestFit)),
+        vec![vec![0, 3], vec![1, 2]]
+    );
+}
+
+#[test]
+fn test_first_fit_decreasing_beats_first_fit() {
+    let solver = BinPacking::new(items(&[2, 5, 4, 7, 1, 3, 8]), 10).unwrap();
+    assert_eq!(solver.pack(BinPackingMethod::FirstFit).bins.len(), 4);
+    let packing = solver.pack(BinPackingMethod::FirstFitDecreasing);
+    assert_eq!(packing.bins.len(), 3);
+    assert_eq!(packing.lower_bound, 3);
+}
+
+#[test]
+fn test_exact_improves_on_first_fit_decreasing() {
+    // FFD packs 5+4, 4+3+2 and 2 into three bins, while 5+3+2 and 4+4+2 fit in two.
+    let weights = [3, 4, 5, 2, 2, 4];
+    let solver = BinPacking::new(items(&weights), 10).unwrap();
+    assert_eq!(
+        solver.pack(BinPackingMethod::FirstFitDecreasing).bins.len(),
+        3
+    );
+    let exact = solver.pack(BinPackingMethod::Exact);
+    assert_valid(&exact, weights.len(), 10);
+    assert_eq!(exact.bins.len(), 2);
+}
+
+#[test]
+fn test_item_too_large() {
+    let error = BinPacking::new(items(&[3, 11, 4]), 10).unwrap_err();
+    assert_eq!(
+        error,
+        BinPackingError::ItemTooLarge {
+            index: 1,
+            weight: 11
+        }
+    );
+    assert_eq!(
+        error.to_string(),
+        "item 1 of weight 11 does not fit in an empty bin"
+    );
+}
+
+#[test]
+fn test_empty_and_weightless_items() {
+    let solver = BinPacking::new(Vec::<Item>::new(), 10).unwrap();
+    assert_eq!(solver.lower_bound(), 0);
+    assert!(solver.pack(BinPackingMethod::Exact).bins.is_empty());
+
+    let solver = BinPacking::new(items(&[0, 0]), 0).unwrap();
+    assert_eq!(solver.lower_bound(), 1);
+    assert_eq!(ids(&solver.pack(BinPackingMethod::Exact)), vec![vec![0, 1]]);
+}
+
+#[test]
+fn test_l2_bound_exceeds_volume_bound() {
+    // Three items above half the capacity need three bins although the
+    // total weight fits in two.
+    let solver = BinPacking::new(items(&[6, 6, 6, 1]), 10).unwrap();
+    assert_eq!(solver.lower_bound(), 3);
+}
+
+#[test]
+fn test_all_methods_valid_and_exact_optimal() {
+    let instances: [&[usize]; 5] = [
+        &[4, 8, 1, 4, 2, 1],
+        &[7, 7, 3, 3, 3, 3, 2, 2],
+        &[9, 8, 2, 2, 5, 4, 3, 6],
+        &[5, 5, 5, 5, 4, 4, 4, 3, 3],
+        &[1, 9, 2, 8, 3, 7, 4, 6, 5],
+    ];
+    let methods = [
+        BinPackingMethod::FirstFit,
+        BinPackingMethod::BestFit,
+        BinPackingMethod::FirstFitDecreasing,
+        BinPackingMethod::Exact,
+    ];
+    for weights in instances {
+        let solver = BinPacking::new(items(weights), 10).unwrap();
+        let optimum = brute_force(weights, 10);
+        assert!(solver.lower_bound() <= optimum, "{:?}", weights);
+        for method in methods {
+            let packing = solver.pack(method);
+            assert_valid(&packing, weights.len(), 10);
+            assert!(packing.bins.len() >= optimum, "{:?} {:?}", weights, method);
+        }
+        assert_eq!(
+            solver.pack(BinPackingMethod::Exact).bins.len(),
+            optimum,
+            "{:?}",
+            weights
+        );
+    }
+}
@@ -0,0 +1,158 @@
+use knap::traits::{Value, Weight};
+use knap::{GreedyKnapsackIterator, KnapsackIterator, Objective, TieBreak};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Item {
+    pub id: String,
+    pub weight: usize,
+    pub value: usize,
+}
+
+impl Item {
+    fn new(id: &str, weight: usize, value: usize) -> Self {
+        Item {
+            id: id.to_string(),
+            weight,
+            value,
+        }
+    }
+}
+
+impl Weight for Item {
+    fn weight(&self) -> usize {
+        self.weight
+    }
+}
+
+impl Value for Item {
+    fn value(&self) -> usize {
+        self.value
+    }
+}
+
+fn items() -> Vec<Item> {
+    vec![
+        Item::new("A", 1, 3),
+        Item::new("B", 1, 3),
+        Item::new("C", 1, 3),
+        Item::new("D", 3, 8),
+        Item::new("E", 4, 9),
+    ]
+}
+
+// Best value over subsets that fit with a count in `min..=max`, by exhaustive search.
+fn brute_force(items: &[Item], capacity: usize, min: usize, max: usize) -> Option<usize> {
+    (0..1u32 << items.len())
+        .filter(|mask| (min..=max).contains(&(mask.count_ones() as usize)))
+        .filter_map(|mask| {
+            let selected = items
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| mask & (1 << i) != 0);
+            let (weight, value) = selected.fold((0, 0), |(w, v), (_, item)| {
+                (w + item.weight, v + item.value)
+            });
+            (weight <= capacity).then_some(value)
+        })
+        .max()
+}
+
+#[test]
+fn test_optimal_respects_limits() {
+    for capacity in 0..=10 {
+        for min in 0..=3 {
+            for max in min..=5 {
+                let solution: Vec<Item> = KnapsackIterator::new(items(), capacity)
+                    .with_min_items(min)
+                    .with_max_items(max)
+                    .collect();
+                let value: usize = solution.iter().map(|item| item.value).sum();
+                match brute_force(&items(), capacity, min, max) {
+                    Some(expected) => {
+                        assert!((min..=max).contains(&solution.len()));
+                        assert_eq!(value, expected, "capacity {capacity}, min {min}, max {max}");
+                    }
+                    None => assert!(solution.is_empty()),
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_optimal_respects_min_items_alone() {
+    let n = items().len();
+    for capacity in 0..=10 {
+        for min in 0..=n + 1 {
+            for tie_break in [None, Some(TieBreak::FewestItems)] {
+                let mut iter = KnapsackIterator::new(items(), capacity).with_min_items(min);
+                if let Some(tie_break) = tie_break {
+                    iter = iter.with_tie_break(tie_break);
+                }
+                let solution: Vec<Item> = iter.collect();
+                let weight: usize = solution.iter().map(|item| item.weight).sum();
+                let value: usize = solution.iter().map(|item| item.value).sum();
+                match brute_force(&items(), capacity, min, n) {
+                    Some(expected) if capacity > 0 => {
+                        assert!(solution.len() >= min && weight <= capacity);
+                        assert_eq!(value, expected, "capacity {capacity}, min {min}");
+                    }
+                    _ => assert!(solution.is_empty()),
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_optimal_max_items_with_policies() {
+    // With room for everything, at most two items: D and E.
+    let ids: Vec<String> = KnapsackIterator::new(items(), 10)
+        .with_max_items(2)
+        .with_objectives([Objective::MinimizeWeight])
+        .map(|item| item.id)
+        .collect();
+    assert_eq!(ids, vec!["D", "E"]);
+
+    // Any two of A, B, C fill capacity 2; prefer the pair starting with the largest id.
+    let ids: Vec<String> = KnapsackIterator::new(items(), 2)
+        .with_max_items(2)
+        .with_tie_break(TieBreak::Custom(|a: &[Item], b: &[Item]| {
+            b[0].id.cmp(&a[0].id)
+        }))
+        .map(|item| item.id)
+        .collect();
+    assert_eq!(ids, vec!["B", "C"]);
+}
+
+#[test]
+fn test_greedy_max_items() {
+    let ids: Vec<String> = GreedyKnapsackIterator::new(items(), 10)
+        .with_max_items(2)
+        .map(|item| item.id)
+        .collect();
+    assert_eq!(ids, vec!["A", "B"]);
+}
+
+#[test]
+fn test_greedy_min_items() {
+    let heavy_first = vec![
+        Item::new("Dense", 5, 50),
+        Item::new("X", 2, 2),
+        Item::new("Y", 2, 2),
+        Item::new("Z", 2, 2),
+    ];
+    // The densest item alone would leave no room for two more items.
+    let ids: Vec<String> = GreedyKnapsackIterator::new(heavy_first.clone(), 6)
+        .with_min_items(3)
+        .map(|item| item.id)
+        .collect();
+    assert_eq!(ids, vec!["X", "Y", "Z"]);
+
+    assert_eq!(
+        GreedyKnapsackIterator::new(heavy_first, 5)
+            .with_min_items(3)
+            .count(),
+        0
+    );
+}
@@ -0,0 +1,96 @@
+use knap::change_making::ChangeMaking;
+use knap::traits::Weight;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Coin(pub usize);
+
+impl Weight for Coin {
+    fn weight(&self) -> usize {
+        self.0
+    }
+}
+
+fn coins(denominations: &[usize]) -> Vec<Coin> {
+    denominations.iter().map(|&d| Coin(d)).collect()
+}
+
+fn total(change: &[Coin]) -> usize {
+    change.iter().map(|coin| coin.0).sum()
+}
+
+// Fewest coins summing to `amount` with at most `limits[i]` coins of type `i`.
+fn brute_force(denominations: &[usize], limits: &[usize], amount: usize) -> Option<usize> {
+    let Some((&first, rest)) = denominations.split_first() else {
+        return (amount == 0).then_some(0);
+    };
+    (0..=limits[0])
+        .take_while(|&k| k * first <= amount)
+        .filter_map(|k| brute_force(rest, &limits[1..], amount - k * first).map(|count| count + k))
+        .min()
+}
+
+#[test]
+fn test_change_canonical_system() {
+    let solver = ChangeMaking::new(coins(&[1, 2, 5, 10, 20, 50]));
+    assert_eq!(solver.make_change(88), Some(coins(&[1, 2, 5, 10, 20, 50])));
+    assert_eq!(solver.make_change(40), Some(coins(&[20, 20])));
+}
+
+#[test]
+fn test_change_non_canonical_system() {
+    // Greedy would use 25 + 1 * 5.
+    let solver = ChangeMaking::new(coins(&[1, 10, 25]));
+    assert_eq!(solver.make_change(30), Some(coins(&[10, 10, 10])));
+}
+
+#[test]
+fn test_change_zero_amount_and_infeasible() {
+    let solver = ChangeMaking::new(coins(&[4, 6]));
+    assert_eq!(solver.make_change(0), Some(Vec::new()));
+    assert_eq!(solver.make_change(7), None);
+    assert_eq!(ChangeMaking::new(Vec::<Coin>::new()).make_change(3), None);
+}
+
+#[test]
+fn test_change_ignores_zero_denomination() {
+    let solver = ChangeMaking::new(coins(&[0, 3]));
+    assert_eq!(solver.make_change(6), Some(coins(&[3, 3])));
+    let solver = ChangeMaking::new(coins(&[0, 3])).with_limits([4, 4]);
+    assert_eq!(solver.make_change(6), Some(coins(&[3, 3])));
+}
+
+#[test]
+fn test_change_with_limits() {
+    let solver = ChangeMaking::new(coins(&[1, 5, 10])).with_limits([3, 1, 1]);
+    assert_eq!(solver.make_change(17), Some(coins(&[1, 1, 5, 10])));
+    assert_eq!(solver.make_change(19), None);
+    // Coin types past the end of the limits are unlimited.
+    let solver = ChangeMaking::new(coins(&[1, 5, 10])).with_limits([3]);
+    assert_eq!(solver.make_change(30), Some(coins(&[10, 10, 10])));
+}
+
+#[test]
+fn test_change_matches_brute_force() {
+    let denominations = [3, 7, 11, 4];
+    let limits = [2, 3, 1, 4];
+    let unlimited = ChangeMaking::new(coins(&denominations));
+    let limited = ChangeMaking::new(coins(&denominations)).with_limits(limits);
+    for amount in 0..=60 {
+        let change = unlimited.make_change(amount);
+        let expected = brute_force(&denominations, &[amount; 4], amount);
+        assert_eq!(change.as_ref().map(Vec::len), expected, "amount {}", amount);
+        if let Some(change) = change {
+            assert_eq!(total(&change), amount);
+        }
+
+        let change = limited.make_change(amount);
+        let expected = brute_force(&denominations, &limits, amount);
+        assert_eq!(change.as_ref().map(Vec::len), expected, "amount {}", amount);
+        if let Some(change) = change {
+            assert_eq!(total(&change), amount);
+            for (denomination, limit) in denominations.iter().zip(limits) {
+                assert!(change.iter().filter(|coin| coin.0 == *denomination).count() <= limit);
+            }
+        }
+    }
+}
@@ -0,0 +1,156 @@
+use knap::conflict::{ConflictError, ConflictKnapsackIterator};
+use knap::traits::{Value, Weight};
+use knap::GreedyKnapsackIterator;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Item {
+    pub id: String,
+    pub weight: usize,
+    pub value: usize,
+}
+
+impl Item {
+    fn new(id: &str, weight: usize, value: usize) -> Self {
+        Item {
+            id: id.to_string(),
+            weight,
+            value,
+        }
+    }
+}
+
+impl Weight for Item {
+    fn weight(&self) -> usize {
+        self.weight
+    }
+}
+
+impl Value for Item {
+    fn value(&self) -> usize {
+        self.value
+    }
+}
+
+fn ids(items: impl Iterator<Item = Item>) -> Vec<String> {
+    items.map(|item| item.id).collect()
+}
+
+// Best value over all conflict-free subsets that fit, by exhaustive search.
+fn brute_force(items: &[Item], capacity: usize, conflicts: &[(usize, usize)]) -> usize {
+    (0..1u32 << items.len())
+        .filter(|mask| {
+            conflicts
+                .iter()
+                .all(|&(a, b)| mask & (1 << a) == 0 || mask & (1 << b) == 0)
+        })
+        .filter(|mask| {
+            items
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| mask & (1 << i) != 0)
+                .map(|(_, item)| item.weight)
+                .sum::<usize>()
+                <= capacity
+        })
+        .map(|mask| {
+            items
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| mask & (1 << i) != 0)
+                .map(|(_, item)| item.value)
+                .sum()
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+#[test]
+fn test_without_conflicts_matches_unconstrained() {
+    let items = vec![
+        Item::new("item1", 2, 3),
+        Item::new("item2", 3, 4),
+        Item::new("item3", 4, 5),
+        Item::new("item4", 5, 6),
+    ];
+    let value: usize = ConflictKnapsackIterator::new(items, 7, [])
+        .unwrap()
+        .map(|item| item.value)
+        .sum();
+    assert_eq!(value, 9);
+}
+
+#[test]
+fn test_matches_brute_force() {
+    let items = vec![
+        Item::new("A", 4, 10),
+        Item::new("B", 3, 7),
+        Item::new("C", 5, 12),
+        Item::new("D", 2, 5),
+        Item::new("E", 6, 13),
+        Item::new("F", 1, 2),
+        Item::new("G", 3, 8),
+    ];
+    let conflicts = [(0, 2), (2, 3), (0, 6), (4, 6), (1, 3), (3, 5)];
+    for capacity in 0..=24 {
+        let solution: Vec<Item> = ConflictKnapsackIterator::new(items.clone(), capacity, conflicts)
+            .unwrap()
+            .collect();
+        let weight: usize = solution.iter().map(|item| item.weight).sum();
+        let value: usize = solution.iter().map(|item| item.value).sum();
+        assert!(weight <= capacity);
+        assert_eq!(
+            value,
+            brute_force(&items, capacity, &conflicts),
+            "capacity {capacity}"
+        );
+    }
+}
+
+#[test]
+fn test_greedy_skips_conflicting_items() {
+    let items = vec![
+        Item::new("A", 10, 60),
+        Item::new("B", 20, 100),
+        Item::new("C", 30, 120),
+    ];
+    let selected = ids(GreedyKnapsackIterator::new(items.clone(), 60)
+        .with_conflicts([(0, 1)])
+        .unwrap());
+    assert_eq!(selected, vec!["A", "C"]);
+
+    let selected = ids(GreedyKnapsackIterator::new(items, 60));
+    assert_eq!(selected, vec!["A", "B", "C"]);
+}
+
+#[test]
+fn test_out_of_bounds_conflict() {
+    let items = vec![Item::new("A", 1, 1)];
+    let error = ConflictKnapsackIterator::new(items.clone(), 1, [(0, 3)]).unwrap_err();
+    assert_eq!(error, ConflictError::IndexOutOfBounds(3));
+
+    let error = GreedyKnapsackIterator::new(items, 1)
+        .with_conflicts([(2, 0)])
+        .unwrap_err();
+    assert_eq!(error, ConflictError::IndexOutOfBounds(2));
+}
+
+#[test]
+fn test_greedy_conflicts_with_min_items() {
+    // Taking the dense item D blocks X, leaving only Z, which no longer fits.
+    let items = vec![
+        Item::new("D", 5, 100),
+        Item::new("X", 1, 1),
+        Item::new("Z", 6, 6),
+    ];
+    let selected = ids(GreedyKnapsackIterator::new(items.clone(), 10)
+        .with_conflicts([(0, 1)])
+        .unwrap()
+        .with_min_items(2));
+    assert_eq!(selected, vec!["X", "Z"]);
+
+    // Without the item limit, D is still preferred.
+    let selected = ids(GreedyKnapsackIterator::new(items, 10)
+        .with_conflicts([(0, 1)])
+        .unwrap());
+    assert_eq!(selected, vec!["D"]);
+}
@@ -0,0 +1,162 @@
+use knap::covering::{CoveringKnapsackIterator, GreedyCoveringIterator};
+use knap::traits::{Value, Weight};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Item {
+    pub id: String,
+    pub weight: usize,
+    pub cost: usize,
+}
+
+impl Item {
+    fn new(id: &str, weight: usize, cost: usize) -> Self {
+        Item {
+            id: id.to_string(),
+            weight,
+            cost,
+        }
+    }
+}
+
+impl Weight for Item {
+    fn weight(&self) -> usize {
+        self.weight
+    }
+}
+
+impl Value for Item {
+    fn value(&self) -> usize {
+        self.cost
+    }
+}
+
+fn ids(items: impl Iterator<Item = Item>) -> Vec<String> {
+    items.map(|item| item.id).collect()
+}
+
+fn cost(items: impl Iterator<Item = Item>) -> usize {
+    items.map(|item| item.cost).sum()
+}
+
+// Cheapest cost over all subsets covering the demand, by exhaustive search.
+fn brute_force(items: &[Item], demand: usize) -> Option<usize> {
+    (0..1u32 << items.len())
+        .filter(|mask| {
+            items
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| mask & (1 << i) != 0)
+                .map(|(_, item)| item.weight)
+                .sum::<usize>()
+                >= demand
+        })
+        .map(|mask| {
+            items
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| mask & (1 << i) != 0)
+                .map(|(_, item)| item.cost)
+                .sum()
+        })
+        .min()
+}
+
+#[test]
+fn test_covering_basic() {
+    let items = vec![
+        Item::new("A", 10, 50),
+        Item::new("B", 6, 20),
+        Item::new("C", 5, 20),
+        Item::new("D", 3, 15),
+    ];
+    let selected = ids(CoveringKnapsackIterator::new(items, 11));
+    assert_eq!(selected, vec!["B", "C"]);
+}
+
+#[test]
+fn test_covering_zero_demand() {
+    let items = vec![Item::new("A", 3, 5)];
+    let mut iter = CoveringKnapsackIterator::new(items, 0);
+    assert!(iter.is_feasible());
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn test_covering_infeasible() {
+    let items = vec![Item::new("A", 3, 5), Item::new("B", 4, 1)];
+    let mut iter = CoveringKnapsackIterator::new(items.clone(), 8);
+    assert!(!iter.is_feasible());
+    assert_eq!(iter.next(), None);
+    assert_eq!(GreedyCoveringIterator::new(items, 8).count(), 0);
+}
+
+#[test]
+fn test_covering_free_items() {
+    let items = vec![
+        Item::new("Free", 4, 0),
+        Item::new("A", 3, 7),
+        Item::new("B", 1, 2),
+    ];
+    let selected = ids(CoveringKnapsackIterator::new(items, 5));
+    assert_eq!(selected, vec!["Free", "B"]);
+}
+
+#[test]
+fn test_covering_matches_brute_force() {
+    let items = vec![
+        Item::new("A", 7, 13),
+        Item::new("B", 3, 5),
+        Item::new("C", 4, 9),
+        Item::new("D", 9, 14),
+        Item::new("E", 2, 6),
+        Item::new("F", 5, 8),
+        Item::new("G", 1, 1),
+    ];
+    for demand in 0..=32 {
+        let mut iter = CoveringKnapsackIterator::new(items.clone(), demand);
+        let expected = brute_force(&items, demand);
+        assert_eq!(iter.is_feasible(), expected.is_some(), "demand {}", demand);
+        let selected: Vec<Item> = iter.collect();
+        let weight: usize = selected.iter().map(|item| item.weight).sum();
+        if let Some(expected) = expected {
+            assert!(weight >= demand, "demand {}", demand);
+            assert_eq!(cost(selected.into_iter()), expected, "demand {}", demand);
+        }
+    }
+}
+
+#[test]
+fn test_greedy_covering_takes_single_large_item() {
+    // By ratio, A and B are cheapest per unit but overshoot badly; C alone is cheaper.
+    let items = vec![
+        Item::new("A", 1, 1),
+        Item::new("B", 1, 1),
+        Item::new("C", 10, 15),
+    ];
+    let selected = ids(GreedyCoveringIterator::new(items, 10));
+    assert_eq!(selected, vec!["C"]);
+}
+
+#[test]
+fn test_greedy_covering_within_factor_two() {
+    let items = vec![
+        Item::new("A", 7, 13),
+        Item::new("B", 3, 5),
+        Item::new("C", 4, 9),
+        Item::new("D", 9, 14),
+        Item::new("E", 2, 6),
+        Item::new("F", 5, 8),
+        Item::new("G", 1, 1),
+    ];
+    for demand in 1..=31 {
+        let selected: Vec<Item> = GreedyCoveringIterator::new(items.clone(), demand).collect();
+        let weight: usize = selected.iter().map(|item| item.weight).sum();
+        let optimum = brute_force(&items, demand).unwrap();
+        assert!(weight >= demand, "demand {}", demand);
+        assert!(
+            cost(selected.into_iter()) <= 2 * optimum,
+            "demand {}",
+            demand
+        );
+    }
+}
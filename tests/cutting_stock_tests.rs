@@ -0,0 +1,134 @@
+use knap::cutting_stock::{CuttingPlan, CuttingStock, CuttingStockError, Demand};
+use knap::traits::Weight;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Order {
+    pub id: usize,
+    pub width: usize,
+    pub pieces: usize,
+}
+
+impl Weight for Order {
+    fn weight(&self) -> usize {
+        self.width
+    }
+}
+
+impl Demand for Order {
+    fn demand(&self) -> usize {
+        self.pieces
+    }
+}
+
+fn orders(requests: &[(usize, usize)]) -> Vec<Order> {
+    requests
+        .iter()
+        .enumerate()
+        .map(|(id, &(width, pieces))| Order { id, width, pieces })
+        .collect()
+}
+
+fn assert_valid(plan: &CuttingPlan<Order>, orders: &[Order], roll_width: usize) {
+    let mut produced = vec![0; orders.len()];
+    for pattern in &plan.patterns {
+        assert!(pattern.multiplicity > 0);
+        let width: usize = pattern
+            .pieces
+            .iter()
+            .map(|(order, count)| order.width * count)
+            .sum();
+        assert!(width <= roll_width);
+        for (order, count) in &pattern.pieces {
+            produced[order.id] += count * pattern.multiplicity;
+        }
+    }
+    for order in orders {
+        assert!(produced[order.id] >= order.pieces, "order {}", order.id);
+    }
+    let rolls: usize = plan
+        .patterns
+        .iter()
+        .map(|pattern| pattern.multiplicity)
+        .sum();
+    assert_eq!(plan.rolls, rolls);
+    assert!(plan.lower_bound <= plan.rolls);
+}
+
+#[test]
+fn test_cutting_stock_single_order() {
+    let orders = orders(&[(3, 7)]);
+    let plan = CuttingStock::new(orders.clone(), 10).unwrap().solve();
+    assert_valid(&plan, &orders, 10);
+    assert_eq!(plan.rolls, 3);
+    assert_eq!(plan.lower_bound, 3);
+}
+
+#[test]
+fn test_cutting_stock_generates_mixed_patterns() {
+    // Homogeneous patterns waste a lot: 45 + 45 and 36 + 36 leave 10 and 28.
+    let orders = orders(&[(45, 4), (36, 3), (31, 6)]);
+    let plan = CuttingStock::new(orders.clone(), 100).unwrap().solve();
+    assert_valid(&plan, &orders, 100);
+    assert_eq!(plan.rolls, 5);
+    assert_eq!(plan.lower_bound, 5);
+    assert!(plan.patterns.iter().any(|pattern| pattern.pieces.len() > 1));
+}
+
+#[test]
+fn test_cutting_stock_classic_instance() {
+    let orders = orders(&[(20, 48), (45, 35), (50, 24), (55, 10), (75, 8)]);
+    let plan = CuttingStock::new(orders.clone(), 110).unwrap().solve();
+    assert_valid(&plan, &orders, 110);
+    // The LP relaxation of this instance is 46.25 rolls.
+    assert_eq!(plan.lower_bound, 47);
+    assert!(plan.rolls <= 48);
+}
+
+#[test]
+fn test_cutting_stock_zero_demand() {
+    let orders = orders(&[(30, 0), (0, 0)]);
+    let plan = CuttingStock::new(orders, 100).unwrap().solve();
+    assert!(plan.patterns.is_empty());
+    assert_eq!(plan.rolls, 0);
+    assert_eq!(plan.lower_bound, 0);
+}
+
+#[test]
+fn test_cutting_stock_invalid_width() {
+    let error = CuttingStock::new(orders(&[(30, 2), (120, 1)]), 100).unwrap_err();
+    assert_eq!(
+        error,
+        CuttingStockError::InvalidWidth {
+            index: 1,
+            width: 120
+        }
+    );
+    let error = CuttingStock::new(orders(&[(0, 2)]), 100).unwrap_err();
+    assert_eq!(
+        error,
+        CuttingStockError::InvalidWidth { index: 0, width: 0 }
+    );
+    assert_eq!(
+        error.to_string(),
+        "order 0 has width 0, which is zero or wider than the roll"
+    );
+}
+
+#[test]
+fn test_cutting_stock_various_instances() {
+    let instances: [(&[(usize, usize)], usize); 4] = [
+        (&[(7, 5), (5, 9), (3, 4)], 16),
+        (&[(12, 3), (9, 7), (4, 11), (2, 5)], 25),
+        (&[(50, 1), (25, 3), (10, 7)], 100),
+        (&[(6, 13), (11, 2), (14, 6)], 30),
+    ];
+    for (requests, roll_width) in instances {
+        let orders = orders(requests);
+        let plan = CuttingStock::new(orders.clone(), roll_width)
+            .unwrap()
+            .solve();
+        assert_valid(&plan, &orders, roll_width);
+        let volume: usize = orders.iter().map(|order| order.width * order.pieces).sum();
+        assert!(plan.lower_bound >= volume.div_ceil(roll_width));
+    }
+}
@@ -0,0 +1,85 @@
+use knap::dynamic::DynamicKnapsack;
+use knap::traits::{Value, Weight};
+use knap::KnapsackIterator;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Item {
+    pub id: String,
+    pub weight: usize,
+    pub value: usize,
+}
+
+impl Item {
+    fn new(id: &str, weight: usize, value: usize) -> Self {
+        Item {
+            id: id.to_string(),
+            weight,
+            value,
+        }
+    }
+}
+
+impl Weight for Item {
+    fn weight(&self) -> usize {
+        self.weight
+    }
+}
+
+impl Value for Item {
+    fn value(&self) -> usize {
+        self.value
+    }
+}
+
+fn assert_matches_full_solve(knapsack: &DynamicKnapsack<Item>) {
+    for capacity in 1..=knapsack.max_capacity() {
+        let expected: Vec<Item> =
+            KnapsackIterator::new(knapsack.items().to_vec(), capacity).collect();
+        assert_eq!(knapsack.solution(capacity), Some(expected));
+    }
+}
+
+#[test]
+fn test_insertions() {
+    let mut knapsack = DynamicKnapsack::new(10);
+    assert!(knapsack.is_empty());
+    assert_eq!(knapsack.value(10), Some(0));
+
+    knapsack.insert(Item::new("A", 2, 3));
+    knapsack.extend([Item::new("B", 3, 4), Item::new("C", 4, 5)]);
+    assert_eq!(knapsack.len(), 3);
+    assert_matches_full_solve(&knapsack);
+}
+
+#[test]
+fn test_removals() {
+    let mut knapsack = DynamicKnapsack::new(12);
+    knapsack.extend([
+        Item::new("A", 2, 3),
+        Item::new("B", 3, 4),
+        Item::new("C", 4, 5),
+        Item::new("D", 5, 6),
+    ]);
+
+    let removed = knapsack.remove(1);
+    assert_eq!(removed.id, "B");
+    assert_matches_full_solve(&knapsack);
+
+    knapsack.remove(2);
+    knapsack.insert(Item::new("E", 1, 10));
+    assert_matches_full_solve(&knapsack);
+
+    knapsack.remove(0);
+    knapsack.remove(0);
+    knapsack.remove(0);
+    assert!(knapsack.is_empty());
+    assert_eq!(knapsack.value(12), Some(0));
+}
+
+#[test]
+fn test_out_of_range_capacity() {
+    let mut knapsack = DynamicKnapsack::new(5);
+    knapsack.insert(Item::new("A", 2, 3));
+    assert_eq!(knapsack.value(6), None);
+    assert_eq!(knapsack.solution(6), None);
+}
@@ -0,0 +1,161 @@
+use std::collections::BTreeMap;
+
+use knap::family::{Family, FamilyKnapsackIterator, FamilySetup};
+use knap::traits::{Value, Weight};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Item {
+    pub id: String,
+    pub family: usize,
+    pub weight: usize,
+    pub value: usize,
+}
+
+impl Item {
+    fn new(id: &str, family: usize, weight: usize, value: usize) -> Self {
+        Item {
+            id: id.to_string(),
+            family,
+            weight,
+            value,
+        }
+    }
+}
+
+impl Weight for Item {
+    fn weight(&self) -> usize {
+        self.weight
+    }
+}
+
+impl Value for Item {
+    fn value(&self) -> usize {
+        self.value
+    }
+}
+
+impl Family for Item {
+    fn family(&self) -> usize {
+        self.family
+    }
+}
+
+fn setup(weight: usize, value: usize) -> FamilySetup {
+    FamilySetup { weight, value }
+}
+
+fn ids(items: impl Iterator<Item = Item>) -> Vec<String> {
+    items.map(|item| item.id).collect()
+}
+
+// Total weight and net value of a selection, including family setups.
+fn evaluate(selected: &[&Item], setups: &BTreeMap<usize, FamilySetup>) -> (usize, i64) {
+    let mut families: Vec<usize> = selected.iter().map(|item| item.family).collect();
+    families.sort_unstable();
+    families.dedup();
+    let family_setups = families
+        .iter()
+        .map(|f| setups.get(f).copied().unwrap_or_default());
+    let mut weight: usize = selected.iter().map(|item| item.weight).sum();
+    let mut value: i64 = selected.iter().map(|item| item.value as i64).sum();
+    for setup in family_setups {
+        weight += setup.weight;
+        value -= setup.value as i64;
+    }
+    (weight, value)
+}
+
+// Best net value over all feasible subsets, by exhaustive search.
+fn brute_force(items: &[Item], capacity: usize, setups: &BTreeMap<usize, FamilySetup>) -> i64 {
+    (0..1u32 << items.len())
+        .map(|mask| {
+            let selected: Vec<&Item> = items
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| mask & (1 << i) != 0)
+                .map(|(_, item)| item)
+                .collect();
+            evaluate(&selected, setups)
+        })
+        .filter(|&(weight, _)| weight <= capacity)
+        .map(|(_, value)| value)
+        .max()
+        .unwrap_or(0)
+}
+
+#[test]
+fn test_family_without_setups_matches_plain_knapsack() {
+    let items = vec![
+        Item::new("A", 0, 10, 60),
+        Item::new("B", 1, 20, 100),
+        Item::new("C", 1, 30, 120),
+    ];
+    let selected = ids(FamilyKnapsackIterator::new(items, 50, Vec::new()));
+    assert_eq!(selected, vec!["B", "C"]);
+}
+
+#[test]
+fn test_family_setup_weight_shares_capacity() {
+    let items = vec![
+        Item::new("A", 0, 3, 10),
+        Item::new("B", 1, 3, 9),
+        Item::new("C", 1, 3, 9),
+    ];
+    let setups = vec![(0, setup(2, 0)), (1, setup(1, 0))];
+    let selected = ids(FamilyKnapsackIterator::new(items, 7, setups));
+    assert_eq!(selected, vec!["B", "C"]);
+}
+
+#[test]
+fn test_family_skipped_when_setup_costs_more_than_items() {
+    let items = vec![Item::new("A", 0, 1, 5), Item::new("B", 1, 4, 3)];
+    let setups = vec![(0, setup(0, 6))];
+    let selected = ids(FamilyKnapsackIterator::new(items, 10, setups));
+    assert_eq!(selected, vec!["B"]);
+}
+
+#[test]
+fn test_family_empty_and_zero_capacity() {
+    let empty: Vec<Item> = Vec::new();
+    assert_eq!(
+        FamilyKnapsackIterator::new(empty, 10, Vec::new()).count(),
+        0
+    );
+
+    let items = vec![Item::new("A", 0, 0, 5), Item::new("B", 1, 1, 5)];
+    let selected = ids(FamilyKnapsackIterator::new(items.clone(), 0, Vec::new()));
+    assert_eq!(selected, vec!["A"]);
+    let setups = vec![(0, setup(1, 0))];
+    assert_eq!(FamilyKnapsackIterator::new(items, 0, setups).count(), 0);
+}
+
+#[test]
+fn test_family_matches_brute_force() {
+    let items = vec![
+        Item::new("A", 0, 3, 8),
+        Item::new("B", 0, 4, 9),
+        Item::new("C", 1, 2, 4),
+        Item::new("D", 1, 5, 11),
+        Item::new("E", 2, 1, 3),
+        Item::new("F", 2, 6, 12),
+        Item::new("G", 3, 2, 7),
+        Item::new("H", 0, 1, 2),
+    ];
+    let setups: BTreeMap<usize, FamilySetup> =
+        vec![(0, setup(2, 3)), (1, setup(1, 5)), (2, setup(3, 1))]
+            .into_iter()
+            .collect();
+    for capacity in 0..=30 {
+        let selected: Vec<Item> =
+            FamilyKnapsackIterator::new(items.clone(), capacity, setups.clone()).collect();
+        let refs: Vec<&Item> = selected.iter().collect();
+        let (weight, value) = evaluate(&refs, &setups);
+        assert!(weight <= capacity, "capacity {}", capacity);
+        assert_eq!(
+            value,
+            brute_force(&items, capacity, &setups),
+            "capacity {}",
+            capacity
+        );
+    }
+}
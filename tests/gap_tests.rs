@@ -0,0 +1,157 @@
+use knap::gap::{Assignable, GeneralizedAssignment};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Task {
+    pub weights: Vec<usize>,
+    pub values: Vec<usize>,
+}
+
+impl Task {
+    fn new(weights: &[usize], values: &[usize]) -> Self {
+        Task {
+            weights: weights.to_vec(),
+            values: values.to_vec(),
+        }
+    }
+}
+
+impl Assignable for Task {
+    fn weight_for(&self, agent: usize) -> usize {
+        self.weights[agent]
+    }
+
+    fn value_for(&self, agent: usize) -> usize {
+        self.values[agent]
+    }
+}
+
+fn is_feasible(tasks: &[Task], capacities: &[usize], agents: &[Option<usize>]) -> bool {
+    let mut used = vec![0; capacities.len()];
+    for (task, agent) in tasks.iter().zip(agents) {
+        if let Some(agent) = *agent {
+            used[agent] += task.weight_for(agent);
+        }
+    }
+    used.iter()
+        .zip(capacities)
+        .all(|(used, capacity)| used <= capacity)
+}
+
+fn value(tasks: &[Task], agents: &[Option<usize>]) -> usize {
+    tasks
+        .iter()
+        .zip(agents)
+        .filter_map(|(task, agent)| agent.map(|agent| task.value_for(agent)))
+        .sum()
+}
+
+// Best value over all assignments, by exhaustive search.
+fn brute_force(tasks: &[Task], capacities: &[usize]) -> usize {
+    let choices = capacities.len() + 1;
+    let total = choices.pow(tasks.len() as u32);
+    (0..total)
+        .map(|mut code| {
+            (0..tasks.len())
+                .map(|_| {
+                    let choice = code % choices;
+                    code /= choices;
+                    (choice < capacities.len()).then_some(choice)
+                })
+                .collect::<Vec<Option<usize>>>()
+        })
+        .filter(|agents| is_feasible(tasks, capacities, agents))
+        .map(|agents| value(tasks, &agents))
+        .max()
+        .unwrap_or(0)
+}
+
+fn instance() -> Vec<Task> {
+    vec![
+        Task::new(&[5, 3, 6], &[9, 6, 10]),
+        Task::new(&[4, 6, 2], &[7, 9, 4]),
+        Task::new(&[3, 2, 4], &[4, 5, 6]),
+        Task::new(&[6, 5, 3], &[11, 8, 7]),
+        Task::new(&[2, 4, 5], &[3, 7, 9]),
+        Task::new(&[7, 3, 4], &[12, 5, 8]),
+        Task::new(&[1, 2, 2], &[2, 3, 2]),
+    ]
+}
+
+#[test]
+fn test_gap_single_agent_is_knapsack() {
+    let tasks = vec![
+        Task::new(&[10], &[60]),
+        Task::new(&[20], &[100]),
+        Task::new(&[30], &[120]),
+    ];
+    let assignment = GeneralizedAssignment::new(tasks, [50]).solve();
+    assert_eq!(assignment.agents, vec![None, Some(0), Some(0)]);
+    assert_eq!(assignment.value, 220);
+    assert!(assignment.is_optimal());
+}
+
+#[test]
+fn test_gap_task_too_heavy_for_every_agent() {
+    let tasks = vec![Task::new(&[9, 9], &[5, 5]), Task::new(&[1, 2], &[3, 4])];
+    let assignment = GeneralizedAssignment::new(tasks, [4, 4]).solve();
+    assert_eq!(assignment.agents, vec![None, Some(1)]);
+    assert_eq!(assignment.value, 4);
+}
+
+#[test]
+fn test_gap_empty() {
+    let tasks: Vec<Task> = Vec::new();
+    let assignment = GeneralizedAssignment::new(tasks, [3, 4]).solve();
+    assert!(assignment.agents.is_empty());
+    assert_eq!(assignment.value, 0);
+    assert_eq!(assignment.upper_bound, 0);
+
+    let tasks = vec![Task::new(&[], &[])];
+    let assignment = GeneralizedAssignment::new(tasks, []).solve();
+    assert_eq!(assignment.agents, vec![None]);
+}
+
+#[test]
+fn test_gap_bound_brackets_optimum() {
+    let tasks = instance();
+    for capacities in [[6, 5, 4], [10, 8, 6], [3, 3, 3], [12, 12, 12], [0, 7, 2]] {
+        let assignment = GeneralizedAssignment::new(tasks.clone(), capacities).solve();
+        let optimum = brute_force(&tasks, &capacities);
+        assert!(is_feasible(&tasks, &capacities, &assignment.agents));
+        assert_eq!(assignment.value, value(&tasks, &assignment.agents));
+        assert!(assignment.value <= optimum, "{:?}", capacities);
+        assert!(assignment.upper_bound >= optimum, "{:?}", capacities);
+        // The heuristics should land close to the optimum on such instances.
+        assert!(10 * assignment.value >= 9 * optimum, "{:?}", capacities);
+    }
+}
+
+#[test]
+fn test_gap_bound_with_zero_capacity_agent() {
+    // Task 2 weighs nothing on agent 1, whose capacity is zero.
+    let tasks = vec![
+        Task::new(&[3, 5], &[1, 7]),
+        Task::new(&[2, 4], &[4, 3]),
+        Task::new(&[3, 0], &[8, 8]),
+        Task::new(&[4, 1], &[1, 1]),
+    ];
+    let capacities = [4, 0];
+    let assignment = GeneralizedAssignment::new(tasks.clone(), capacities).solve();
+    let optimum = brute_force(&tasks, &capacities);
+    assert_eq!(optimum, 12);
+    assert!(is_feasible(&tasks, &capacities, &assignment.agents));
+    assert!(assignment.upper_bound >= optimum);
+    assert_eq!(assignment.value, optimum);
+}
+
+#[test]
+fn test_gap_zero_iterations_uses_heuristic_only() {
+    let tasks = instance();
+    let capacities = [6, 5, 4];
+    let assignment = GeneralizedAssignment::new(tasks.clone(), capacities)
+        .with_iterations(0)
+        .solve();
+    assert!(is_feasible(&tasks, &capacities, &assignment.agents));
+    assert!(assignment.value > 0);
+    assert!(assignment.upper_bound >= brute_force(&tasks, &capacities));
+}
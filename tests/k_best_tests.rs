@@ -0,0 +1,109 @@
+use knap::optimal::{KBestKnapsackIterator, KnapsackIterator};
+use knap::traits::{Value, Weight};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Item {
+    pub id: String,
+    pub weight: usize,
+    pub value: usize,
+}
+
+impl Item {
+    fn new(id: &str, weight: usize, value: usize) -> Self {
+        Item {
+            id: id.to_string(),
+            weight,
+            value,
+        }
+    }
+}
+
+impl Weight for Item {
+    fn weight(&self) -> usize {
+        self.weight
+    }
+}
+
+impl Value for Item {
+    fn value(&self) -> usize {
+        self.value
+    }
+}
+
+fn total_value(solution: &[Item]) -> usize {
+    solution.iter().map(|item| item.value).sum()
+}
+
+#[test]
+fn test_values_are_non_increasing() {
+    let items = vec![
+        Item::new("item1", 2, 3),
+        Item::new("item2", 3, 4),
+        Item::new("item3", 4, 5),
+        Item::new("item4", 5, 6),
+    ];
+    let values: Vec<usize> = KBestKnapsackIterator::new(items, 7, 10)
+        .map(|solution| total_value(&solution))
+        .collect();
+    // Only nine subsets fit: {}, four singletons, {1,2}, {1,3}, {1,4}, {2,3}.
+    assert_eq!(values.len(), 9);
+    assert_eq!(values[0], 9);
+    assert!(values.windows(2).all(|pair| pair[0] >= pair[1]));
+}
+
+#[test]
+fn test_enumerates_every_feasible_subset_once() {
+    let items = vec![
+        Item::new("A", 1, 1),
+        Item::new("B", 2, 2),
+        Item::new("C", 3, 4),
+    ];
+    // Feasible subsets for capacity 3: {}, A, B, C, AB.
+    let mut solutions: Vec<Vec<String>> = KBestKnapsackIterator::new(items, 3, 100)
+        .map(|solution| solution.into_iter().map(|item| item.id).collect())
+        .collect();
+    assert_eq!(solutions.len(), 5);
+    assert_eq!(solutions[0], vec!["C".to_string()]);
+    solutions.sort();
+    solutions.dedup();
+    assert_eq!(solutions.len(), 5);
+}
+
+#[test]
+fn test_solutions_are_feasible() {
+    let items = vec![
+        Item::new("A", 10, 60),
+        Item::new("B", 20, 100),
+        Item::new("C", 30, 120),
+        Item::new("D", 25, 110),
+    ];
+    for solution in KBestKnapsackIterator::new(items, 50, 8) {
+        assert!(solution.iter().map(|item| item.weight).sum::<usize>() <= 50);
+    }
+}
+
+#[test]
+fn test_zero_k_yields_nothing() {
+    let items = vec![Item::new("A", 1, 1)];
+    assert_eq!(KBestKnapsackIterator::new(items, 1, 0).count(), 0);
+}
+
+#[test]
+fn test_first_solution_matches_knapsack_iterator() {
+    let items = vec![
+        Item::new("Zero", 0, 2),
+        Item::new("A", 1, 1),
+        Item::new("B", 2, 3),
+        Item::new("C", 3, 4),
+    ];
+    for capacity in 1..=6 {
+        let first = KBestKnapsackIterator::new(items.clone(), capacity, 1).next();
+        let optimal: Vec<Item> = KnapsackIterator::new(items.clone(), capacity).collect();
+        assert_eq!(first, Some(optimal), "capacity {capacity}");
+    }
+
+    // At capacity zero, the weightless item is still a feasible improvement.
+    let first = KBestKnapsackIterator::new(items, 0, 1).next().unwrap();
+    let ids: Vec<String> = first.into_iter().map(|item| item.id).collect();
+    assert_eq!(ids, vec!["Zero"]);
+}
@@ -0,0 +1,179 @@
+use knap::multi_period::{MultiPeriodError, MultiPeriodKnapsackIterator, Periods};
+use knap::traits::{Value, Weight};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Item {
+    pub id: usize,
+    pub weight: usize,
+    pub value: usize,
+    pub periods: Vec<usize>,
+}
+
+impl Item {
+    fn new(id: usize, weight: usize, value: usize, periods: &[usize]) -> Self {
+        Item {
+            id,
+            weight,
+            value,
+            periods: periods.to_vec(),
+        }
+    }
+}
+
+impl Weight for Item {
+    fn weight(&self) -> usize {
+        self.weight
+    }
+}
+
+impl Value for Item {
+    fn value(&self) -> usize {
+        self.value
+    }
+}
+
+impl Periods for Item {
+    fn periods(&self) -> Vec<usize> {
+        self.periods.clone()
+    }
+}
+
+fn is_feasible(items: &[Item], capacities: &[usize]) -> bool {
+    (0..capacities.len()).all(|p| {
+        items
+            .iter()
+            .filter(|item| item.periods.contains(&p))
+            .map(|item| item.weight)
+            .sum::<usize>()
+            <= capacities[p]
+    })
+}
+
+fn value(items: &[Item]) -> usize {
+    items.iter().map(|item| item.value).sum()
+}
+
+// Best value over all feasible subsets, by exhaustive search.
+fn brute_force(items: &[Item], capacities: &[usize]) -> usize {
+    (0..1u32 << items.len())
+        .map(|mask| {
+            items
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| mask & (1 << i) != 0)
+                .map(|(_, item)| item.clone())
+                .collect::<Vec<Item>>()
+        })
+        .filter(|selected| is_feasible(selected, capacities))
+        .map(|selected| value(&selected))
+        .max()
+        .unwrap_or(0)
+}
+
+fn instance() -> Vec<Item> {
+    vec![
+        Item::new(0, 3, 8, &[0, 1]),
+        Item::new(1, 2, 5, &[0]),
+        Item::new(2, 4, 9, &[1, 2]),
+        Item::new(3, 1, 3, &[2]),
+        Item::new(4, 5, 12, &[0, 1, 2]),
+        Item::new(5, 2, 4, &[1]),
+        Item::new(6, 3, 6, &[2, 3]),
+        Item::new(7, 2, 7, &[3]),
+        Item::new(8, 4, 5, &[0, 3]),
+        Item::new(9, 1, 2, &[1, 3]),
+    ]
+}
+
+#[test]
+fn test_single_period_matches_plain_knapsack() {
+    let items = vec![
+        Item::new(0, 10, 60, &[0]),
+        Item::new(1, 20, 100, &[0]),
+        Item::new(2, 30, 120, &[0]),
+    ];
+    let ids: Vec<usize> = MultiPeriodKnapsackIterator::new(items, [50])
+        .unwrap()
+        .map(|item| item.id)
+        .collect();
+    assert_eq!(ids, vec![1, 2]);
+}
+
+#[test]
+fn test_items_without_periods_are_free() {
+    let items = vec![Item::new(0, 9, 4, &[]), Item::new(1, 2, 3, &[0])];
+    let ids: Vec<usize> = MultiPeriodKnapsackIterator::new(items, [1])
+        .unwrap()
+        .map(|item| item.id)
+        .collect();
+    assert_eq!(ids, vec![0]);
+}
+
+#[test]
+fn test_period_out_of_bounds() {
+    let items = vec![Item::new(0, 1, 1, &[0]), Item::new(1, 1, 1, &[2])];
+    let error = MultiPeriodKnapsackIterator::new(items, [5, 5]).unwrap_err();
+    assert_eq!(
+        error,
+        MultiPeriodError::PeriodOutOfBounds {
+            index: 1,
+            period: 2
+        }
+    );
+}
+
+#[test]
+fn test_exact_matches_brute_force() {
+    let items = instance();
+    for capacities in [
+        [5, 5, 5, 5],
+        [8, 6, 7, 4],
+        [3, 9, 2, 6],
+        [10, 10, 10, 10],
+        [0, 4, 4, 0],
+    ] {
+        let selected: Vec<Item> = MultiPeriodKnapsackIterator::new(items.clone(), capacities)
+            .unwrap()
+            .collect();
+        assert!(is_feasible(&selected, &capacities), "{:?}", capacities);
+        assert_eq!(
+            value(&selected),
+            brute_force(&items, &capacities),
+            "{:?}",
+            capacities
+        );
+    }
+}
+
+#[test]
+fn test_heuristic_is_feasible_and_close() {
+    let items = instance();
+    for capacities in [
+        [5, 5, 5, 5],
+        [8, 6, 7, 4],
+        [3, 9, 2, 6],
+        [10, 10, 10, 10],
+        [0, 4, 4, 0],
+    ] {
+        let selected: Vec<Item> = MultiPeriodKnapsackIterator::new(items.clone(), capacities)
+            .unwrap()
+            .with_max_table_size(0)
+            .collect();
+        let optimum = brute_force(&items, &capacities);
+        assert!(is_feasible(&selected, &capacities), "{:?}", capacities);
+        assert!(4 * value(&selected) >= 3 * optimum, "{:?}", capacities);
+    }
+}
+
+#[test]
+fn test_heuristic_with_single_round() {
+    let items = instance();
+    let capacities = [8, 6, 7, 4];
+    let selected: Vec<Item> = MultiPeriodKnapsackIterator::new(items, capacities)
+        .unwrap()
+        .with_max_table_size(0)
+        .with_rounds(1)
+        .collect();
+    assert!(is_feasible(&selected, &capacities));
+    assert!(!selected.is_empty());
+}
@@ -0,0 +1,101 @@
+use knap::traits::{Value, Weight};
+use knap::{KnapsackIterator, Objective, TieBreak};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Item {
+    pub id: String,
+    pub weight: usize,
+    pub value: usize,
+    pub priority: usize,
+}
+
+impl Item {
+    fn new(id: &str, weight: usize, value: usize, priority: usize) -> Self {
+        Item {
+            id: id.to_string(),
+            weight,
+            value,
+            priority,
+        }
+    }
+}
+
+impl Weight for Item {
+    fn weight(&self) -> usize {
+        self.weight
+    }
+}
+
+impl Value for Item {
+    fn value(&self) -> usize {
+        self.value
+    }
+}
+
+fn ids(items: impl Iterator<Item = Item>) -> Vec<String> {
+    items.map(|item| item.id).collect()
+}
+
+#[test]
+fn test_value_remains_primary() {
+    let items = vec![Item::new("Light", 1, 5, 0), Item::new("Heavy", 10, 50, 0)];
+    let iter = KnapsackIterator::new(items, 10).with_objectives([Objective::MinimizeWeight]);
+    assert_eq!(ids(iter), vec!["Heavy"]);
+}
+
+#[test]
+fn test_minimize_weight_then_count() {
+    let items = vec![
+        Item::new("A", 2, 3, 0),
+        Item::new("B", 1, 3, 0),
+        Item::new("C", 3, 6, 0),
+        Item::new("D", 4, 6, 0),
+    ];
+    // With capacity 4, value 9 is only reached by {B, C}.
+    let iter = KnapsackIterator::new(items.clone(), 4).with_objectives([Objective::MinimizeCount]);
+    assert_eq!(ids(iter), vec!["B", "C"]);
+
+    // With capacity 3, value 6 is reached by {A, B} and {C}, both weighing 3.
+    let iter = KnapsackIterator::new(items.clone(), 3).with_objectives([Objective::MinimizeWeight]);
+    assert_eq!(ids(iter), vec!["A", "B"]);
+
+    let iter = KnapsackIterator::new(items.clone(), 3)
+        .with_objectives([Objective::MinimizeWeight, Objective::MinimizeCount]);
+    assert_eq!(ids(iter), vec!["C"]);
+
+    let iter = KnapsackIterator::new(items, 3).with_objectives([Objective::MinimizeCount]);
+    assert_eq!(ids(iter), vec!["C"]);
+}
+
+#[test]
+fn test_custom_score_objectives() {
+    let items = vec![Item::new("Low", 2, 4, 1), Item::new("High", 2, 4, 9)];
+    let iter = KnapsackIterator::new(items.clone(), 2)
+        .with_objectives([Objective::Maximize(|item: &Item| item.priority)]);
+    assert_eq!(ids(iter), vec!["High"]);
+
+    let iter = KnapsackIterator::new(items, 2)
+        .with_objectives([Objective::Minimize(|item: &Item| item.priority)]);
+    assert_eq!(ids(iter), vec!["Low"]);
+}
+
+#[test]
+fn test_tie_break_applies_after_objectives() {
+    let items = vec![
+        Item::new("A", 1, 2, 0),
+        Item::new("B", 1, 2, 0),
+        Item::new("C", 2, 4, 0),
+    ];
+    // Both {A, B} and {C} weigh 2, so the tie-break picks between them.
+    let iter = KnapsackIterator::new(items.clone(), 2)
+        .with_objectives([Objective::MinimizeWeight])
+        .with_tie_break(TieBreak::FewestItems);
+    assert_eq!(ids(iter), vec!["C"]);
+
+    let iter = KnapsackIterator::new(items, 2)
+        .with_objectives([Objective::MinimizeWeight])
+        .with_tie_break(TieBreak::Custom(|a: &[Item], b: &[Item]| {
+            b.len().cmp(&a.len())
+        }));
+    assert_eq!(ids(iter), vec!["A", "B"]);
+}
@@ -0,0 +1,124 @@
+use knap::online::{OnlineError, OnlineKnapsack, OnlineKnapsackIterator, OnlinePolicy};
+use knap::traits::{Value, Weight};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Item {
+    pub id: String,
+    pub weight: usize,
+    pub value: usize,
+}
+
+impl Item {
+    fn new(id: &str, weight: usize, value: usize) -> Self {
+        Item {
+            id: id.to_string(),
+            weight,
+            value,
+        }
+    }
+}
+
+impl Weight for Item {
+    fn weight(&self) -> usize {
+        self.weight
+    }
+}
+
+impl Value for Item {
+    fn value(&self) -> usize {
+        self.value
+    }
+}
+
+#[test]
+fn test_greedy_accepts_everything_that_fits() {
+    let mut knapsack = OnlineKnapsack::new(5, OnlinePolicy::Greedy).unwrap();
+    assert!(knapsack.offer(Item::new("A", 3, 1)));
+    assert!(!knapsack.offer(Item::new("B", 3, 100)));
+    assert!(knapsack.offer(Item::new("C", 2, 1)));
+    assert_eq!(knapsack.remaining_capacity(), 0);
+    assert_eq!(knapsack.accepted().len(), 2);
+}
+
+#[test]
+fn test_threshold_starts_at_lower_bound() {
+    let policy = OnlinePolicy::Threshold {
+        lower: 1.0,
+        upper: 10.0,
+    };
+    let mut knapsack = OnlineKnapsack::new(100, policy).unwrap();
+    assert!(!knapsack.offer(Item::new("TooSparse", 10, 5)));
+    assert!(knapsack.offer(Item::new("AtLower", 10, 10)));
+    assert_eq!(knapsack.used_capacity(), 10);
+}
+
+#[test]
+fn test_threshold_rises_with_utilization() {
+    let policy = OnlinePolicy::Threshold {
+        lower: 1.0,
+        upper: 10.0,
+    };
+    let arrivals = (0..100).map(|i| Item::new(&format!("low{i}"), 1, 2));
+    let accepted: Vec<Item> = OnlineKnapsackIterator::new(arrivals, 100, policy)
+        .unwrap()
+        .collect();
+
+    // Density 2 is accepted until the threshold exceeds it, well before the knapsack is full.
+    assert!(!accepted.is_empty());
+    assert!(accepted.len() < 100);
+
+    let mut knapsack = OnlineKnapsack::new(100, policy).unwrap();
+    for item in accepted {
+        knapsack.offer(item);
+    }
+    assert!(!knapsack.offer(Item::new("Low", 1, 2)));
+    assert!(knapsack.offer(Item::new("High", 1, 10)));
+}
+
+#[test]
+fn test_zero_weight_items_always_accepted() {
+    let mut knapsack = OnlineKnapsack::new(0, OnlinePolicy::FixedThreshold(5.0)).unwrap();
+    assert!(knapsack.offer(Item::new("Free", 0, 0)));
+    assert!(!knapsack.offer(Item::new("Heavy", 1, 100)));
+}
+
+#[test]
+fn test_invalid_policies() {
+    let error = OnlineKnapsack::<Item>::new(
+        10,
+        OnlinePolicy::Threshold {
+            lower: 5.0,
+            upper: 1.0,
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        error,
+        OnlineError::InvalidThresholdBounds {
+            lower: 5.0,
+            upper: 1.0
+        }
+    );
+
+    let bounds = [
+        (0.0, 1.0),
+        (-1.0, 1.0),
+        (1.0, f64::INFINITY),
+        (f64::NAN, 1.0),
+    ];
+    for (lower, upper) in bounds {
+        let policy = OnlinePolicy::Threshold { lower, upper };
+        assert!(matches!(
+            OnlineKnapsack::<Item>::new(10, policy),
+            Err(OnlineError::InvalidThresholdBounds { .. })
+        ));
+    }
+
+    for density in [-1.0, f64::NAN, f64::INFINITY] {
+        let policy = OnlinePolicy::FixedThreshold(density);
+        assert!(matches!(
+            OnlineKnapsackIterator::new(Vec::<Item>::new(), 10, policy),
+            Err(OnlineError::InvalidThreshold(_))
+        ));
+    }
+}
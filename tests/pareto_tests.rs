@@ -0,0 +1,101 @@
+use knap::pareto::{ParetoFrontIterator, ParetoSolution, Sense};
+use knap::traits::{Values, Weight};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Item {
+    pub id: String,
+    pub weight: usize,
+    pub profit: usize,
+    pub risk: usize,
+}
+
+impl Item {
+    fn new(id: &str, weight: usize, profit: usize, risk: usize) -> Self {
+        Item {
+            id: id.to_string(),
+            weight,
+            profit,
+            risk,
+        }
+    }
+}
+
+impl Weight for Item {
+    fn weight(&self) -> usize {
+        self.weight
+    }
+}
+
+impl Values for Item {
+    fn values(&self) -> Vec<usize> {
+        vec![self.profit, self.risk]
+    }
+}
+
+fn objectives(front: &[ParetoSolution<Item>]) -> Vec<Vec<usize>> {
+    front
+        .iter()
+        .map(|solution| solution.objectives.clone())
+        .collect()
+}
+
+#[test]
+fn test_maximize_both_objectives() {
+    let items = vec![
+        Item::new("A", 3, 10, 1),
+        Item::new("B", 3, 1, 10),
+        Item::new("C", 3, 5, 5),
+    ];
+    let front: Vec<_> = ParetoFrontIterator::new(items, 3).collect();
+    assert_eq!(
+        objectives(&front),
+        vec![vec![10, 1], vec![5, 5], vec![1, 10]]
+    );
+}
+
+#[test]
+fn test_profit_against_risk() {
+    let items = vec![
+        Item::new("Safe", 2, 3, 0),
+        Item::new("Risky", 2, 9, 7),
+        Item::new("Dominated", 2, 2, 3),
+    ];
+    let front: Vec<_> = ParetoFrontIterator::new(items.clone(), 2)
+        .with_senses([Sense::Maximize, Sense::Minimize])
+        .collect();
+    assert_eq!(objectives(&front), vec![vec![9, 7], vec![3, 0]]);
+    assert!(front
+        .iter()
+        .all(|solution| solution.items.iter().all(|item| item.id != "Dominated")));
+
+    let front: Vec<_> = ParetoFrontIterator::new(items, 4)
+        .with_senses([Sense::Maximize, Sense::Minimize])
+        .collect();
+    assert_eq!(
+        objectives(&front),
+        vec![vec![12, 7], vec![5, 3], vec![3, 0]]
+    );
+}
+
+#[test]
+fn test_solutions_respect_capacity() {
+    let items = vec![
+        Item::new("A", 4, 8, 2),
+        Item::new("B", 3, 5, 6),
+        Item::new("C", 2, 3, 1),
+        Item::new("D", 5, 9, 9),
+    ];
+    for solution in ParetoFrontIterator::new(items, 7) {
+        let weight: usize = solution.items.iter().map(|item| item.weight).sum();
+        assert_eq!(weight, solution.weight);
+        assert!(weight <= 7);
+    }
+}
+
+#[test]
+fn test_empty_items() {
+    let items: Vec<Item> = Vec::new();
+    let front: Vec<_> = ParetoFrontIterator::new(items, 10).collect();
+    assert_eq!(front.len(), 1);
+    assert!(front[0].items.is_empty());
+}
@@ -0,0 +1,125 @@
+use knap::precedence::{PrecedenceError, PrecedenceKnapsackIterator};
+use knap::traits::{Value, Weight};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Item {
+    pub id: String,
+    pub weight: usize,
+    pub value: usize,
+}
+
+impl Item {
+    fn new(id: &str, weight: usize, value: usize) -> Self {
+        Item {
+            id: id.to_string(),
+            weight,
+            value,
+        }
+    }
+}
+
+impl Weight for Item {
+    fn weight(&self) -> usize {
+        self.weight
+    }
+}
+
+impl Value for Item {
+    fn value(&self) -> usize {
+        self.value
+    }
+}
+
+fn items() -> Vec<Item> {
+    vec![
+        Item::new("A", 3, 2),
+        Item::new("B", 2, 9),
+        Item::new("C", 4, 7),
+        Item::new("D", 1, 6),
+        Item::new("E", 5, 11),
+        Item::new("F", 2, 1),
+        Item::new("G", 3, 8),
+    ]
+}
+
+// Best value over all precedence-closed subsets that fit, by exhaustive search.
+fn brute_force(items: &[Item], capacity: usize, precedences: &[(usize, usize)]) -> usize {
+    let mut best = 0;
+    for mask in 0..1u32 << items.len() {
+        let selected = |i: usize| mask & (1 << i) != 0;
+        if precedences
+            .iter()
+            .any(|&(a, b)| selected(b) && !selected(a))
+        {
+            continue;
+        }
+        let (weight, value) = (0..items.len())
+            .filter(|&i| selected(i))
+            .fold((0, 0), |(w, v), i| {
+                (w + items[i].weight, v + items[i].value)
+            });
+        if weight <= capacity {
+            best = best.max(value);
+        }
+    }
+    best
+}
+
+fn check_against_brute_force(precedences: &[(usize, usize)]) {
+    for capacity in 0..=20 {
+        let solution: Vec<Item> =
+            PrecedenceKnapsackIterator::new(items(), capacity, precedences.to_vec())
+                .unwrap()
+                .collect();
+        let ids: Vec<&str> = solution.iter().map(|item| item.id.as_str()).collect();
+        for &(a, b) in precedences {
+            let has = |i: usize| ids.contains(&items()[i].id.as_str());
+            assert!(!has(b) || has(a), "{ids:?} violates ({a}, {b})");
+        }
+        assert!(solution.iter().map(|item| item.weight).sum::<usize>() <= capacity);
+        let value: usize = solution.iter().map(|item| item.value).sum();
+        assert_eq!(
+            value,
+            brute_force(&items(), capacity, precedences),
+            "capacity {capacity}"
+        );
+    }
+}
+
+#[test]
+fn test_forest_precedences() {
+    check_against_brute_force(&[(0, 1), (0, 2), (2, 3), (4, 5), (4, 6)]);
+}
+
+#[test]
+fn test_dag_precedences() {
+    check_against_brute_force(&[(0, 1), (2, 1), (1, 3), (2, 4), (5, 4), (4, 6), (3, 6)]);
+}
+
+#[test]
+fn test_no_precedences() {
+    check_against_brute_force(&[]);
+}
+
+#[test]
+fn test_cycle_is_reported() {
+    let error =
+        PrecedenceKnapsackIterator::new(items(), 10, [(0, 1), (1, 2), (2, 0), (3, 4)]).unwrap_err();
+    match error {
+        PrecedenceError::Cycle(mut cycle) => {
+            cycle.sort_unstable();
+            assert_eq!(cycle, vec![0, 1, 2]);
+        }
+        other => panic!("unexpected error {other:?}"),
+    }
+}
+
+#[test]
+fn test_index_out_of_bounds() {
+    let error = PrecedenceKnapsackIterator::new(items(), 10, [(0, 9)]).unwrap_err();
+    assert_eq!(error, PrecedenceError::IndexOutOfBounds(9));
+    assert_eq!(
+        error.to_string(),
+        "precedence refers to item 9, which is out of bounds"
+    );
+}
@@ -0,0 +1,79 @@
+use knap::profile::CapacityProfile;
+use knap::traits::{Value, Weight};
+use knap::KnapsackIterator;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Item {
+    pub id: String,
+    pub weight: usize,
+    pub value: usize,
+}
+
+impl Item {
+    fn new(id: &str, weight: usize, value: usize) -> Self {
+        Item {
+            id: id.to_string(),
+            weight,
+            value,
+        }
+    }
+}
+
+impl Weight for Item {
+    fn weight(&self) -> usize {
+        self.weight
+    }
+}
+
+impl Value for Item {
+    fn value(&self) -> usize {
+        self.value
+    }
+}
+
+fn items() -> Vec<Item> {
+    vec![
+        Item::new("item1", 2, 3),
+        Item::new("item2", 3, 4),
+        Item::new("item3", 4, 5),
+        Item::new("item4", 5, 6),
+    ]
+}
+
+#[test]
+fn test_matches_knapsack_iterator_for_every_capacity() {
+    let profile = CapacityProfile::new(items(), 12);
+    for capacity in 1..=12 {
+        let expected: Vec<Item> = KnapsackIterator::new(items(), capacity).collect();
+        let expected_value: usize = expected.iter().map(|item| item.value).sum();
+        assert_eq!(profile.value(capacity), Some(expected_value));
+        assert_eq!(profile.solution(capacity), Some(expected));
+    }
+}
+
+#[test]
+fn test_steps_are_strictly_increasing() {
+    let profile = CapacityProfile::new(items(), 14);
+    let steps = profile.steps();
+    assert_eq!(steps[0], (0, 0));
+    assert!(steps
+        .windows(2)
+        .all(|pair| pair[0].0 < pair[1].0 && pair[0].1 < pair[1].1));
+    assert_eq!(steps.last(), Some(&(14, 18)));
+    assert_eq!(profile.values().len(), 15);
+}
+
+#[test]
+fn test_queries_beyond_max_capacity() {
+    let profile = CapacityProfile::new(items(), 5);
+    assert_eq!(profile.max_capacity(), 5);
+    assert_eq!(profile.value(6), None);
+    assert_eq!(profile.solution(6), None);
+}
+
+#[test]
+fn test_from_knapsack_iterator() {
+    let profile = KnapsackIterator::new(items(), 7).capacity_profile();
+    assert_eq!(profile.max_capacity(), 7);
+    assert_eq!(profile.value(7), Some(9));
+}
@@ -0,0 +1,186 @@
+use knap::quadratic::{QuadraticError, QuadraticKnapsackIterator, QuadraticMethod};
+use knap::traits::{Value, Weight};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Item {
+    pub id: usize,
+    pub weight: usize,
+    pub value: usize,
+}
+
+impl Item {
+    fn new(id: usize, weight: usize, value: usize) -> Self {
+        Item { id, weight, value }
+    }
+}
+
+impl Weight for Item {
+    fn weight(&self) -> usize {
+        self.weight
+    }
+}
+
+impl Value for Item {
+    fn value(&self) -> usize {
+        self.value
+    }
+}
+
+const METHODS: [QuadraticMethod; 3] = [
+    QuadraticMethod::Greedy,
+    QuadraticMethod::LocalSearch,
+    QuadraticMethod::Exact,
+];
+
+fn solve(
+    items: &[Item],
+    capacity: usize,
+    pairs: &[(usize, usize, usize)],
+    method: QuadraticMethod,
+) -> Vec<usize> {
+    QuadraticKnapsackIterator::new(items.to_vec(), capacity, pairs.to_vec(), method)
+        .unwrap()
+        .map(|item| item.id)
+        .collect()
+}
+
+fn profit(items: &[Item], pairs: &[(usize, usize, usize)], selected: &[usize]) -> usize {
+    let linear: usize = selected.iter().map(|&id| items[id].value).sum();
+    let quadratic: usize = pairs
+        .iter()
+        .filter(|&&(a, b, _)| selected.contains(&a) && selected.contains(&b))
+        .map(|&(_, _, profit)| profit)
+        .sum();
+    linear + quadratic
+}
+
+fn weight(items: &[Item], selected: &[usize]) -> usize {
+    selected.iter().map(|&id| items[id].weight).sum()
+}
+
+// Best profit over all feasible subsets, by exhaustive search.
+fn brute_force(items: &[Item], capacity: usize, pairs: &[(usize, usize, usize)]) -> usize {
+    (0..1u32 << items.len())
+        .map(|mask| {
+            (0..items.len())
+                .filter(|i| mask & (1 << i) != 0)
+                .collect::<Vec<usize>>()
+        })
+        .filter(|selected| weight(items, selected) <= capacity)
+        .map(|selected| profit(items, pairs, &selected))
+        .max()
+        .unwrap_or(0)
+}
+
+fn instance() -> (Vec<Item>, Vec<(usize, usize, usize)>) {
+    let items = vec![
+        Item::new(0, 5, 3),
+        Item::new(1, 4, 6),
+        Item::new(2, 6, 2),
+        Item::new(3, 3, 4),
+        Item::new(4, 7, 9),
+        Item::new(5, 2, 1),
+        Item::new(6, 4, 0),
+        Item::new(7, 5, 5),
+        Item::new(8, 1, 2),
+        Item::new(9, 3, 0),
+    ];
+    let pairs = vec![
+        (0, 2, 7),
+        (0, 6, 4),
+        (1, 3, 2),
+        (2, 6, 8),
+        (3, 9, 6),
+        (4, 7, 1),
+        (5, 9, 5),
+        (6, 8, 3),
+        (1, 7, 4),
+        (2, 5, 2),
+        (8, 9, 3),
+    ];
+    (items, pairs)
+}
+
+#[test]
+fn test_quadratic_without_pairs() {
+    let items = vec![
+        Item::new(0, 10, 60),
+        Item::new(1, 20, 100),
+        Item::new(2, 30, 120),
+    ];
+    // Dropping C first leaves the greedy solution stuck at A and B.
+    assert_eq!(solve(&items, 50, &[], QuadraticMethod::Greedy), vec![0, 1]);
+    assert_eq!(
+        solve(&items, 50, &[], QuadraticMethod::LocalSearch),
+        vec![1, 2]
+    );
+    assert_eq!(solve(&items, 50, &[], QuadraticMethod::Exact), vec![1, 2]);
+}
+
+#[test]
+fn test_quadratic_pair_profit_changes_choice() {
+    let items = vec![Item::new(0, 4, 10), Item::new(1, 3, 4), Item::new(2, 3, 4)];
+    for method in METHODS {
+        assert_eq!(
+            solve(&items, 6, &[(1, 2, 5)], method),
+            vec![1, 2],
+            "{:?}",
+            method
+        );
+    }
+}
+
+#[test]
+fn test_quadratic_repeated_and_diagonal_pairs() {
+    let items = vec![Item::new(0, 3, 1), Item::new(1, 3, 1), Item::new(2, 3, 6)];
+    // The two (0, 1) pairs add up to 4, and the diagonal entry lifts item 0 to 3.
+    let pairs = [(0, 1, 2), (1, 0, 2), (0, 0, 2)];
+    let selected = solve(&items, 6, &pairs, QuadraticMethod::Exact);
+    assert_eq!(selected, vec![0, 2]);
+}
+
+#[test]
+fn test_quadratic_empty_and_zero_capacity() {
+    for method in METHODS {
+        assert!(solve(&[], 10, &[], method).is_empty());
+        let items = vec![Item::new(0, 0, 2), Item::new(1, 1, 5)];
+        assert_eq!(solve(&items, 0, &[(0, 1, 3)], method), vec![0]);
+    }
+}
+
+#[test]
+fn test_quadratic_pair_out_of_bounds() {
+    let items = vec![Item::new(0, 1, 1)];
+    let error =
+        QuadraticKnapsackIterator::new(items, 1, [(0, 1, 1)], QuadraticMethod::Greedy).unwrap_err();
+    assert_eq!(error, QuadraticError::IndexOutOfBounds(1));
+}
+
+#[test]
+fn test_quadratic_exact_matches_brute_force() {
+    let (items, pairs) = instance();
+    for capacity in 0..=40 {
+        let selected = solve(&items, capacity, &pairs, QuadraticMethod::Exact);
+        assert!(weight(&items, &selected) <= capacity);
+        assert_eq!(
+            profit(&items, &pairs, &selected),
+            brute_force(&items, capacity, &pairs),
+            "capacity {}",
+            capacity
+        );
+    }
+}
+
+#[test]
+fn test_quadratic_heuristics_are_feasible_and_ordered() {
+    let (items, pairs) = instance();
+    for capacity in 0..=40 {
+        let greedy = solve(&items, capacity, &pairs, QuadraticMethod::Greedy);
+        let local = solve(&items, capacity, &pairs, QuadraticMethod::LocalSearch);
+        let exact = solve(&items, capacity, &pairs, QuadraticMethod::Exact);
+        assert!(weight(&items, &greedy) <= capacity);
+        assert!(weight(&items, &local) <= capacity);
+        assert!(profit(&items, &pairs, &greedy) <= profit(&items, &pairs, &local));
+        assert!(profit(&items, &pairs, &local) <= profit(&items, &pairs, &exact));
+    }
+}
@@ -0,0 +1,99 @@
+use knap::robust::{worst_case_weight, RobustKnapsackIterator, WeightDeviation};
+use knap::traits::{Value, Weight};
+use knap::KnapsackIterator;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Item {
+    pub id: String,
+    pub weight: usize,
+    pub deviation: usize,
+    pub value: usize,
+}
+
+impl Item {
+    fn new(id: &str, weight: usize, deviation: usize, value: usize) -> Self {
+        Item {
+            id: id.to_string(),
+            weight,
+            deviation,
+            value,
+        }
+    }
+}
+
+impl Weight for Item {
+    fn weight(&self) -> usize {
+        self.weight
+    }
+}
+
+impl WeightDeviation for Item {
+    fn weight_deviation(&self) -> usize {
+        self.deviation
+    }
+}
+
+impl Value for Item {
+    fn value(&self) -> usize {
+        self.value
+    }
+}
+
+fn items() -> Vec<Item> {
+    vec![
+        Item::new("A", 3, 3, 9),
+        Item::new("B", 4, 1, 8),
+        Item::new("C", 2, 2, 5),
+        Item::new("D", 5, 0, 9),
+        Item::new("E", 1, 4, 4),
+    ]
+}
+
+// Best value over all subsets whose worst case fits, by exhaustive search.
+fn brute_force(items: &[Item], capacity: usize, gamma: usize) -> usize {
+    (0..1u32 << items.len())
+        .map(|mask| {
+            items
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| mask & (1 << i) != 0)
+                .map(|(_, item)| item.clone())
+                .collect::<Vec<Item>>()
+        })
+        .filter(|subset| worst_case_weight(subset, gamma) <= capacity)
+        .map(|subset| subset.iter().map(|item| item.value).sum())
+        .max()
+        .unwrap_or(0)
+}
+
+#[test]
+fn test_gamma_zero_is_nominal_knapsack() {
+    let robust: Vec<Item> = RobustKnapsackIterator::new(items(), 10, 0).collect();
+    let nominal: Vec<Item> = KnapsackIterator::new(items(), 10).collect();
+    let value = |solution: &[Item]| solution.iter().map(|item| item.value).sum::<usize>();
+    assert_eq!(value(&robust), value(&nominal));
+}
+
+#[test]
+fn test_matches_brute_force() {
+    for gamma in 0..=5 {
+        for capacity in 0..=16 {
+            let solution: Vec<Item> =
+                RobustKnapsackIterator::new(items(), capacity, gamma).collect();
+            assert!(worst_case_weight(&solution, gamma) <= capacity);
+            let value: usize = solution.iter().map(|item| item.value).sum();
+            assert_eq!(
+                value,
+                brute_force(&items(), capacity, gamma),
+                "gamma {gamma}, capacity {capacity}"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_worst_case_weight() {
+    assert_eq!(worst_case_weight(&items(), 0), 15);
+    assert_eq!(worst_case_weight(&items(), 2), 22);
+    assert_eq!(worst_case_weight(&items(), 10), 25);
+}
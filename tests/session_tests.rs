@@ -0,0 +1,75 @@
+use knap::session::KnapsackSession;
+use knap::traits::{Value, Weight};
+use knap::KnapsackIterator;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Item {
+    pub id: String,
+    pub weight: usize,
+    pub value: usize,
+}
+
+impl Item {
+    fn new(id: &str, weight: usize, value: usize) -> Self {
+        Item {
+            id: id.to_string(),
+            weight,
+            value,
+        }
+    }
+}
+
+impl Weight for Item {
+    fn weight(&self) -> usize {
+        self.weight
+    }
+}
+
+impl Value for Item {
+    fn value(&self) -> usize {
+        self.value
+    }
+}
+
+fn items() -> Vec<Item> {
+    vec![
+        Item::new("A", 10, 60),
+        Item::new("B", 20, 100),
+        Item::new("C", 30, 120),
+        Item::new("D", 5, 20),
+    ]
+}
+
+#[test]
+fn test_queries_match_fresh_solves() {
+    let session = KnapsackSession::new(items(), 70);
+    for capacity in (1..=70).step_by(3) {
+        let expected: Vec<Item> = KnapsackIterator::new(items(), capacity).collect();
+        let solved: Vec<Item> = session.solution(capacity).unwrap();
+        assert_eq!(solved, expected);
+        assert_eq!(
+            session.value(capacity),
+            Some(expected.iter().map(|item| item.value).sum())
+        );
+    }
+}
+
+#[test]
+fn test_batch_queries() {
+    let session = KnapsackSession::new(items(), 50);
+    let values: Vec<Option<usize>> = session
+        .solutions([5, 50, 51])
+        .into_iter()
+        .map(|solution| solution.map(|items| items.iter().map(|item| item.value).sum()))
+        .collect();
+    assert_eq!(values, vec![Some(20), Some(220), None]);
+}
+
+#[test]
+fn test_out_of_range_capacity() {
+    let session = KnapsackSession::new(items(), 10);
+    assert_eq!(session.max_capacity(), 10);
+    assert!(session.solution(11).is_none());
+    assert_eq!(session.value(11), None);
+    assert_eq!(session.value(10), Some(60));
+}
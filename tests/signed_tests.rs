@@ -0,0 +1,221 @@
+use knap::signed::{SignedKnapsackIterator, SignedMethod};
+use knap::traits::{SignedValue, SignedWeight, Value, Weight};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Item {
+    pub id: usize,
+    pub weight: i64,
+    pub value: i64,
+}
+
+impl Item {
+    fn new(id: usize, weight: i64, value: i64) -> Self {
+        Item { id, weight, value }
+    }
+}
+
+impl SignedWeight for Item {
+    fn signed_weight(&self) -> i64 {
+        self.weight
+    }
+}
+
+impl SignedValue for Item {
+    fn signed_value(&self) -> i64 {
+        self.value
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnsignedItem {
+    pub weight: usize,
+    pub value: usize,
+}
+
+impl Weight for UnsignedItem {
+    fn weight(&self) -> usize {
+        self.weight
+    }
+}
+
+impl Value for UnsignedItem {
+    fn value(&self) -> usize {
+        self.value
+    }
+}
+
+fn totals(items: &[Item]) -> (i64, i64) {
+    (
+        items.iter().map(|item| item.weight).sum(),
+        items.iter().map(|item| item.value).sum(),
+    )
+}
+
+// Best value over all subsets that fit, by exhaustive search.
+fn brute_force(items: &[Item], capacity: i64) -> Option<i64> {
+    (0..1u32 << items.len())
+        .map(|mask| {
+            items
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| mask & (1 << i) != 0)
+                .map(|(_, item)| item.clone())
+                .collect::<Vec<Item>>()
+        })
+        .map(|selected| totals(&selected))
+        .filter(|&(weight, _)| weight <= capacity)
+        .map(|(_, value)| value)
+        .max()
+}
+
+#[test]
+fn test_signed_accepts_unsigned_items() {
+    let items = vec![
+        UnsignedItem {
+            weight: 10,
+            value: 60,
+        },
+        UnsignedItem {
+            weight: 20,
+            value: 100,
+        },
+        UnsignedItem {
+            weight: 30,
+            value: 120,
+        },
+    ];
+    let selected: Vec<UnsignedItem> =
+        SignedKnapsackIterator::new(items.clone(), 50, SignedMethod::Optimal).collect();
+    assert_eq!(selected, items[1..].to_vec());
+}
+
+#[test]
+fn test_signed_forced_items() {
+    let items = vec![
+        Item::new(0, -2, 3),
+        Item::new(1, 0, 4),
+        Item::new(2, 5, -1),
+        Item::new(3, 0, -1),
+        Item::new(4, 0, 0),
+        Item::new(5, 3, 5),
+    ];
+    let ids: Vec<usize> = SignedKnapsackIterator::new(items, 1, SignedMethod::Optimal)
+        .map(|item| item.id)
+        .collect();
+    // Item 0 frees room for item 5; items 2 and 3 only lose value.
+    assert_eq!(ids, vec![0, 1, 5]);
+}
+
+#[test]
+fn test_signed_complemented_item_left_out() {
+    // Taking B frees room, but the loss outweighs what C adds.
+    let items = vec![
+        Item::new(0, 4, 10),
+        Item::new(1, -3, -8),
+        Item::new(2, 3, 7),
+    ];
+    let ids: Vec<usize> = SignedKnapsackIterator::new(items, 4, SignedMethod::Optimal)
+        .map(|item| item.id)
+        .collect();
+    assert_eq!(ids, vec![0]);
+}
+
+#[test]
+fn test_signed_negative_capacity() {
+    let items = vec![Item::new(0, -3, -1), Item::new(1, 2, 5)];
+    let mut iter = SignedKnapsackIterator::new(items.clone(), -2, SignedMethod::Optimal);
+    assert!(iter.is_feasible());
+    let ids: Vec<usize> = iter.map(|item| item.id).collect();
+    assert_eq!(ids, vec![0]);
+
+    let mut iter = SignedKnapsackIterator::new(items, -4, SignedMethod::Optimal);
+    assert!(!iter.is_feasible());
+    assert_eq!(iter.next(), None);
+}
+
+fn mixed_items() -> Vec<Item> {
+    vec![
+        Item::new(0, 4, 7),
+        Item::new(1, -2, -3),
+        Item::new(2, 3, -2),
+        Item::new(3, -1, 4),
+        Item::new(4, 5, 9),
+        Item::new(5, -4, -5),
+        Item::new(6, 2, 3),
+        Item::new(7, 0, 2),
+        Item::new(8, 6, 8),
+        Item::new(9, -3, -1),
+    ]
+}
+
+#[test]
+fn test_signed_matches_brute_force() {
+    let items = mixed_items();
+    for capacity in -12..=20 {
+        let mut iter = SignedKnapsackIterator::new(items.clone(), capacity, SignedMethod::Optimal);
+        let expected = brute_force(&items, capacity);
+        assert_eq!(
+            iter.is_feasible(),
+            expected.is_some(),
+            "capacity {}",
+            capacity
+        );
+        let selected: Vec<Item> = iter.collect();
+        if let Some(expected) = expected {
+            let (weight, value) = totals(&selected);
+            assert!(weight <= capacity, "capacity {}", capacity);
+            assert_eq!(value, expected, "capacity {}", capacity);
+        }
+    }
+}
+
+#[test]
+fn test_signed_greedy_is_feasible_and_bounded() {
+    let items = mixed_items();
+    for capacity in -12..=20 {
+        let mut iter = SignedKnapsackIterator::new(items.clone(), capacity, SignedMethod::Greedy);
+        let expected = brute_force(&items, capacity);
+        assert_eq!(
+            iter.is_feasible(),
+            expected.is_some(),
+            "capacity {}",
+            capacity
+        );
+        let selected: Vec<Item> = iter.collect();
+        match expected {
+            Some(expected) => {
+                let (weight, value) = totals(&selected);
+                assert!(weight <= capacity, "capacity {}", capacity);
+                assert!(value <= expected, "capacity {}", capacity);
+                // Free room and free value are always taken, pure losses never.
+                let ids: Vec<usize> = selected.iter().map(|item| item.id).collect();
+                assert!(
+                    ids.contains(&3) && ids.contains(&7),
+                    "capacity {}",
+                    capacity
+                );
+                assert!(!ids.contains(&2), "capacity {}", capacity);
+            }
+            None => assert!(selected.is_empty()),
+        }
+    }
+}
+
+#[test]
+fn test_signed_greedy_takes_densest_items() {
+    // Greedy takes the denser A, then drops B because leaving it out still
+    // fits; keeping B and taking C instead of A is worth more.
+    let items = vec![
+        Item::new(0, 4, 9),
+        Item::new(1, -3, -2),
+        Item::new(2, 6, 12),
+    ];
+    let greedy: Vec<usize> = SignedKnapsackIterator::new(items.clone(), 4, SignedMethod::Greedy)
+        .map(|item| item.id)
+        .collect();
+    assert_eq!(greedy, vec![0]);
+    let optimal: Vec<usize> = SignedKnapsackIterator::new(items, 4, SignedMethod::Optimal)
+        .map(|item| item.id)
+        .collect();
+    assert_eq!(optimal, vec![1, 2]);
+}
@@ -0,0 +1,125 @@
+use knap::stochastic::{StochasticKnapsackIterator, StochasticWeight, WeightDistribution};
+use knap::traits::Value;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Job {
+    pub id: String,
+    pub duration: WeightDistribution,
+    pub value: usize,
+}
+
+impl Job {
+    fn new(id: &str, duration: WeightDistribution, value: usize) -> Self {
+        Job {
+            id: id.to_string(),
+            duration,
+            value,
+        }
+    }
+}
+
+impl StochasticWeight for Job {
+    fn weight_distribution(&self) -> WeightDistribution {
+        self.duration.clone()
+    }
+}
+
+impl Value for Job {
+    fn value(&self) -> usize {
+        self.value
+    }
+}
+
+fn fixed(weight: usize) -> WeightDistribution {
+    WeightDistribution::Discrete(vec![(weight, 1.0)])
+}
+
+fn ids(iter: StochasticKnapsackIterator<Job>) -> Vec<String> {
+    iter.map(|job| job.id).collect()
+}
+
+#[test]
+fn test_deterministic_weights_match_exact_solver() {
+    let jobs = vec![
+        Job::new("A", fixed(10), 60),
+        Job::new("B", fixed(20), 100),
+        Job::new("C", fixed(30), 120),
+    ];
+    let mut iter = StochasticKnapsackIterator::new(jobs, 50, 0.99);
+    assert_eq!(iter.estimated_probability(), 1.0);
+    assert_eq!(ids(iter), vec!["B", "C"]);
+}
+
+#[test]
+fn test_confidence_level_changes_solution() {
+    let jobs = vec![
+        Job::new(
+            "Risky",
+            WeightDistribution::Discrete(vec![(2, 0.7), (20, 0.3)]),
+            50,
+        ),
+        Job::new("Safe", fixed(8), 30),
+    ];
+    // Taking both fits only when the risky job is short, about 70% of the time.
+    assert_eq!(
+        ids(StochasticKnapsackIterator::new(jobs.clone(), 10, 0.5)),
+        vec!["Risky", "Safe"]
+    );
+    assert_eq!(
+        ids(StochasticKnapsackIterator::new(jobs.clone(), 10, 0.95)),
+        vec!["Safe"]
+    );
+
+    let mut iter = StochasticKnapsackIterator::new(jobs, 10, 0.5)
+        .with_samples(500)
+        .with_seed(7);
+    let probability = iter.estimated_probability();
+    assert!(probability > 0.6 && probability < 0.8);
+}
+
+#[test]
+fn test_normal_weights() {
+    let jobs = vec![
+        Job::new(
+            "A",
+            WeightDistribution::Normal {
+                mean: 5.0,
+                std_dev: 1.0,
+            },
+            10,
+        ),
+        Job::new(
+            "B",
+            WeightDistribution::Normal {
+                mean: 5.0,
+                std_dev: 1.0,
+            },
+            10,
+        ),
+    ];
+    // Two jobs average exactly the capacity, so they rarely fit together with high confidence.
+    let mut iter = StochasticKnapsackIterator::new(jobs, 10, 0.95);
+    assert!(iter.estimated_probability() >= 0.95);
+    assert_eq!(iter.count(), 1);
+}
+
+#[test]
+fn test_fallback_when_candidate_limit_is_reached() {
+    let jobs = vec![
+        Job::new(
+            "Risky",
+            WeightDistribution::Discrete(vec![(2, 0.7), (20, 0.3)]),
+            50,
+        ),
+        Job::new("Safe", fixed(8), 30),
+    ];
+    // On expected weights (8 and 8) only one job fits, so the heuristic misses the pair.
+    let iter = StochasticKnapsackIterator::new(jobs, 10, 0.6).with_max_candidates(0);
+    assert_eq!(ids(iter), vec!["Risky"]);
+}
+
+#[test]
+fn test_distribution_mean() {
+    let distribution = WeightDistribution::Discrete(vec![(2, 0.5), (4, 0.5)]);
+    assert_eq!(distribution.mean(), 3.0);
+}
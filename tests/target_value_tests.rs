@@ -0,0 +1,118 @@
+use knap::optimal::KnapsackIterator;
+use knap::traits::{Value, Weight};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Item {
+    pub id: usize,
+    pub weight: usize,
+    pub value: usize,
+}
+
+impl Item {
+    fn new(id: usize, weight: usize, value: usize) -> Self {
+        Item { id, weight, value }
+    }
+}
+
+impl Weight for Item {
+    fn weight(&self) -> usize {
+        self.weight
+    }
+}
+
+impl Value for Item {
+    fn value(&self) -> usize {
+        self.value
+    }
+}
+
+// Lightest weight over all subsets reaching the target, by exhaustive search.
+fn brute_force(items: &[Item], target: usize) -> Option<usize> {
+    (0..1u32 << items.len())
+        .map(|mask| {
+            items
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| mask & (1 << i) != 0)
+                .fold((0, 0), |(weight, value), (_, item)| {
+                    (weight + item.weight, value + item.value)
+                })
+        })
+        .filter(|&(_, value)| value >= target)
+        .map(|(weight, _)| weight)
+        .min()
+}
+
+#[test]
+fn test_target_value_basic() {
+    let items = vec![
+        Item::new(0, 10, 60),
+        Item::new(1, 20, 100),
+        Item::new(2, 30, 120),
+    ];
+    let solver = KnapsackIterator::new(items, 60);
+    let (weight, subset) = solver.min_weight_for_value(170).unwrap();
+    assert_eq!(weight, 40);
+    assert_eq!(
+        subset.iter().map(|item| item.id).collect::<Vec<_>>(),
+        vec![0, 2]
+    );
+}
+
+#[test]
+fn test_target_value_zero_target() {
+    let items = vec![Item::new(0, 3, 5)];
+    let solver = KnapsackIterator::new(items, 0);
+    assert_eq!(solver.min_weight_for_value(0), Some((0, Vec::new())));
+}
+
+#[test]
+fn test_target_value_unreachable() {
+    let items = vec![Item::new(0, 3, 5), Item::new(1, 4, 6)];
+    assert_eq!(
+        KnapsackIterator::new(items.clone(), 100).min_weight_for_value(12),
+        None
+    );
+    // Reachable, but not within the capacity.
+    assert_eq!(
+        KnapsackIterator::new(items.clone(), 6).min_weight_for_value(11),
+        None
+    );
+    assert_eq!(
+        KnapsackIterator::new(items, 7)
+            .min_weight_for_value(11)
+            .map(|(weight, _)| weight),
+        Some(7)
+    );
+}
+
+#[test]
+fn test_target_value_is_inverse_of_optimal_value() {
+    let items = vec![
+        Item::new(0, 5, 9),
+        Item::new(1, 3, 4),
+        Item::new(2, 7, 13),
+        Item::new(3, 2, 3),
+        Item::new(4, 6, 8),
+        Item::new(5, 4, 7),
+        Item::new(6, 1, 1),
+    ];
+    let total_weight: usize = items.iter().map(|item| item.weight).sum();
+    let solver = KnapsackIterator::new(items.clone(), total_weight);
+    for target in 0..=50 {
+        let result = solver.min_weight_for_value(target);
+        assert_eq!(
+            result.as_ref().map(|(weight, _)| *weight),
+            brute_force(&items, target)
+        );
+        if let Some((weight, subset)) = result {
+            assert_eq!(subset.iter().map(|item| item.weight).sum::<usize>(), weight);
+            assert!(subset.iter().map(|item| item.value).sum::<usize>() >= target);
+            // The optimal value for that weight reaches the target too.
+            let value: usize = KnapsackIterator::new(items.clone(), weight)
+                .map(|item| item.value)
+                .sum();
+            assert!(value >= target);
+        }
+    }
+}
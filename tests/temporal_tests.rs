@@ -0,0 +1,171 @@
+use knap::temporal::{peak_usage, Interval, TemporalKnapsackIterator, TemporalMethod};
+use knap::traits::{Value, Weight};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Item {
+    pub id: usize,
+    pub weight: usize,
+    pub value: usize,
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Item {
+    fn new(id: usize, weight: usize, value: usize, start: usize, end: usize) -> Self {
+        Item {
+            id,
+            weight,
+            value,
+            start,
+            end,
+        }
+    }
+}
+
+impl Weight for Item {
+    fn weight(&self) -> usize {
+        self.weight
+    }
+}
+
+impl Value for Item {
+    fn value(&self) -> usize {
+        self.value
+    }
+}
+
+impl Interval for Item {
+    fn start(&self) -> usize {
+        self.start
+    }
+
+    fn end(&self) -> usize {
+        self.end
+    }
+}
+
+fn solve(items: &[Item], capacity: usize, method: TemporalMethod) -> Vec<Item> {
+    TemporalKnapsackIterator::new(items.to_vec(), capacity, method).collect()
+}
+
+fn value(items: &[Item]) -> usize {
+    items.iter().map(|item| item.value).sum()
+}
+
+// Peak usage computed instant by instant.
+fn naive_peak(items: &[Item]) -> usize {
+    let horizon = items.iter().map(|item| item.end).max().unwrap_or(0);
+    (0..horizon)
+        .map(|t| {
+            items
+                .iter()
+                .filter(|item| item.start <= t && t < item.end)
+                .map(|item| item.weight)
+                .sum()
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+// Best value over all feasible subsets, by exhaustive search.
+fn brute_force(items: &[Item], capacity: usize) -> usize {
+    (0..1u32 << items.len())
+        .map(|mask| {
+            items
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| mask & (1 << i) != 0)
+                .map(|(_, item)| item.clone())
+                .collect::<Vec<Item>>()
+        })
+        .filter(|selected| naive_peak(selected) <= capacity)
+        .map(|selected| value(&selected))
+        .max()
+        .unwrap_or(0)
+}
+
+fn instance() -> Vec<Item> {
+    vec![
+        Item::new(0, 3, 9, 0, 4),
+        Item::new(1, 2, 5, 2, 6),
+        Item::new(2, 4, 11, 5, 9),
+        Item::new(3, 1, 2, 0, 10),
+        Item::new(4, 5, 14, 3, 5),
+        Item::new(5, 2, 6, 7, 10),
+        Item::new(6, 3, 4, 1, 3),
+        Item::new(7, 2, 7, 4, 8),
+        Item::new(8, 4, 8, 8, 10),
+        Item::new(9, 1, 3, 6, 7),
+        Item::new(10, 6, 10, 9, 10),
+    ]
+}
+
+#[test]
+fn test_peak_usage() {
+    let items = instance();
+    assert_eq!(peak_usage(&items), naive_peak(&items));
+    assert_eq!(peak_usage::<Item>(&[]), 0);
+    // Touching intervals do not overlap.
+    let touching = vec![Item::new(0, 2, 1, 0, 3), Item::new(1, 2, 1, 3, 5)];
+    assert_eq!(peak_usage(&touching), 2);
+}
+
+#[test]
+fn test_same_interval_is_plain_knapsack() {
+    let items = vec![
+        Item::new(0, 10, 60, 0, 5),
+        Item::new(1, 20, 100, 0, 5),
+        Item::new(2, 30, 120, 0, 5),
+    ];
+    let ids: Vec<usize> = solve(&items, 50, TemporalMethod::Exact)
+        .iter()
+        .map(|item| item.id)
+        .collect();
+    assert_eq!(ids, vec![1, 2]);
+}
+
+#[test]
+fn test_disjoint_intervals_share_capacity() {
+    let items = vec![
+        Item::new(0, 5, 3, 0, 2),
+        Item::new(1, 5, 3, 2, 4),
+        Item::new(2, 5, 3, 4, 6),
+    ];
+    for method in [TemporalMethod::Greedy, TemporalMethod::Exact] {
+        assert_eq!(solve(&items, 5, method).len(), 3);
+    }
+}
+
+#[test]
+fn test_empty_interval_occupies_nothing() {
+    let items = vec![Item::new(0, 50, 4, 3, 3), Item::new(1, 2, 1, 0, 4)];
+    let selected = solve(&items, 2, TemporalMethod::Exact);
+    assert_eq!(selected.len(), 2);
+    assert_eq!(peak_usage(&selected), 2);
+}
+
+#[test]
+fn test_exact_matches_brute_force() {
+    let items = instance();
+    for capacity in 0..=12 {
+        let selected = solve(&items, capacity, TemporalMethod::Exact);
+        assert!(naive_peak(&selected) <= capacity, "capacity {}", capacity);
+        assert_eq!(
+            value(&selected),
+            brute_force(&items, capacity),
+            "capacity {}",
+            capacity
+        );
+    }
+}
+
+#[test]
+fn test_greedy_is_feasible() {
+    let items = instance();
+    for capacity in 0..=12 {
+        let greedy = solve(&items, capacity, TemporalMethod::Greedy);
+        let exact = solve(&items, capacity, TemporalMethod::Exact);
+        assert!(naive_peak(&greedy) <= capacity, "capacity {}", capacity);
+        assert!(value(&greedy) <= value(&exact), "capacity {}", capacity);
+    }
+}
@@ -0,0 +1,142 @@
+use std::cmp::Ordering;
+
+use knap::traits::{Value, Weight};
+use knap::{GreedyKnapsackIterator, KnapsackIterator, TieBreak};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Item {
+    pub id: String,
+    pub weight: usize,
+    pub value: usize,
+}
+
+impl Item {
+    fn new(id: &str, weight: usize, value: usize) -> Self {
+        Item {
+            id: id.to_string(),
+            weight,
+            value,
+        }
+    }
+}
+
+impl Weight for Item {
+    fn weight(&self) -> usize {
+        self.weight
+    }
+}
+
+impl Value for Item {
+    fn value(&self) -> usize {
+        self.value
+    }
+}
+
+fn ids(items: impl Iterator<Item = Item>) -> Vec<String> {
+    items.map(|item| item.id).collect()
+}
+
+fn tied_items() -> Vec<Item> {
+    vec![
+        Item::new("A", 1, 2),
+        Item::new("B", 1, 2),
+        Item::new("C", 2, 4),
+        Item::new("D", 3, 4),
+    ]
+}
+
+#[test]
+fn test_optimal_fewest_items() {
+    let iter = KnapsackIterator::new(tied_items(), 2).with_tie_break(TieBreak::FewestItems);
+    assert_eq!(ids(iter), vec!["C"]);
+}
+
+#[test]
+fn test_optimal_lightest_weight() {
+    let items = vec![Item::new("Heavy", 3, 4), Item::new("Light", 2, 4)];
+    let iter = KnapsackIterator::new(items, 3).with_tie_break(TieBreak::LightestWeight);
+    assert_eq!(ids(iter), vec!["Light"]);
+}
+
+#[test]
+fn test_optimal_lowest_indices() {
+    let iter = KnapsackIterator::new(tied_items(), 2).with_tie_break(TieBreak::LowestIndices);
+    assert_eq!(ids(iter), vec!["A", "B"]);
+
+    let items = vec![Item::new("A", 1, 1), Item::new("Zero", 0, 0)];
+    let iter = KnapsackIterator::new(items, 1).with_tie_break(TieBreak::LowestIndices);
+    assert_eq!(ids(iter), vec!["A"]);
+}
+
+#[test]
+fn test_optimal_policies_at_zero_capacity() {
+    let items = vec![Item::new("Zero", 0, 5), Item::new("A", 1, 1)];
+    let plain: Vec<String> = ids(KnapsackIterator::new(items.clone(), 0));
+    assert!(plain.is_empty());
+
+    for policy in [
+        TieBreak::FewestItems,
+        TieBreak::LightestWeight,
+        TieBreak::LowestIndices,
+    ] {
+        let iter = KnapsackIterator::new(items.clone(), 0).with_tie_break(policy);
+        assert_eq!(ids(iter), plain);
+    }
+    let iter = KnapsackIterator::new(items, 0).with_max_items(1);
+    assert_eq!(ids(iter), plain);
+}
+
+#[test]
+fn test_optimal_custom_comparator() {
+    // Prefer the solution containing the lexicographically largest id.
+    fn largest_id(a: &[Item], b: &[Item]) -> Ordering {
+        let max_id = |s: &[Item]| s.iter().map(|item| item.id.clone()).max();
+        max_id(b).cmp(&max_id(a))
+    }
+    let iter = KnapsackIterator::new(tied_items(), 2).with_tie_break(TieBreak::Custom(largest_id));
+    assert_eq!(ids(iter), vec!["C"]);
+
+    // Remaining ties fall back to the lowest indices.
+    let iter = KnapsackIterator::new(tied_items(), 3).with_tie_break(TieBreak::Custom(largest_id));
+    assert_eq!(ids(iter), vec!["A", "C"]);
+}
+
+#[test]
+fn test_optimal_custom_comparator_with_many_ties() {
+    // Any 8 of the 16 identical items are optimal: 12870 solutions.
+    let items: Vec<Item> = (0..16)
+        .map(|i| Item::new(&format!("{i:02}"), 1, 1))
+        .collect();
+    fn largest_ids(a: &[Item], b: &[Item]) -> Ordering {
+        let ids = |s: &[Item]| s.iter().map(|item| item.id.clone()).collect::<Vec<_>>();
+        ids(b).cmp(&ids(a))
+    }
+    let iter = KnapsackIterator::new(items, 8).with_tie_break(TieBreak::Custom(largest_ids));
+    assert_eq!(
+        ids(iter),
+        vec!["08", "09", "10", "11", "12", "13", "14", "15"]
+    );
+}
+
+#[test]
+fn test_greedy_policies_order_equal_ratios() {
+    let items = vec![Item::new("Small", 1, 2), Item::new("Big", 2, 4)];
+
+    let iter = GreedyKnapsackIterator::new(items.clone(), 2).with_tie_break(TieBreak::FewestItems);
+    assert_eq!(ids(iter), vec!["Big"]);
+
+    let iter =
+        GreedyKnapsackIterator::new(items.clone(), 2).with_tie_break(TieBreak::LightestWeight);
+    assert_eq!(ids(iter), vec!["Small"]);
+
+    let iter = GreedyKnapsackIterator::new(items, 2).with_tie_break(TieBreak::LowestIndices);
+    assert_eq!(ids(iter), vec!["Small"]);
+}
+
+#[test]
+fn test_policies_are_consistent_across_solvers() {
+    let items = vec![Item::new("Small", 1, 2), Item::new("Big", 2, 4)];
+    let optimal = KnapsackIterator::new(items.clone(), 2).with_tie_break(TieBreak::FewestItems);
+    let greedy = GreedyKnapsackIterator::new(items, 2).with_tie_break(TieBreak::FewestItems);
+    assert_eq!(ids(optimal), ids(greedy));
+}